@@ -1,4 +1,4 @@
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "float"))]
 mod std {
     use size::consts;
     use size::{Base, Size};
@@ -42,9 +42,9 @@ mod std {
     }
 }
 
-#[cfg(not(feature = "std"))]
+#[cfg(not(all(feature = "std", feature = "float")))]
 fn main() {}
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "float"))]
 fn main() {
     std::main()
 }
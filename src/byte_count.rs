@@ -0,0 +1,422 @@
+use crate::consts::*;
+use crate::from_str::parse_bytes;
+use crate::{IntoSize, ParseSizeError, Size, TryFromSizeError};
+use core::convert::TryFrom;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// An unsigned companion to [`Size`], backed by a `u64` instead of an `i64`.
+///
+/// A `Size` can express negative sizes (e.g. the difference between two sizes) but tops out at
+/// just under 8 EiB. `ByteCount` trades the ability to go negative for the full `u64` range of up
+/// to 16 EiB, making it a better fit for counters (bytes transferred, disk usage, etc.) that can
+/// never drop below zero.
+///
+/// `ByteCount` offers the same generic constructors as `Size` (clamping out-of-range and negative
+/// values to [`ByteCount::MAX`] and [`ByteCount::ZERO`], respectively), is formatted by the same
+/// [`SizeFormatter`](crate::SizeFormatter) rules, and may be parsed from text or (with the `serde`
+/// feature) deserialized in the same way.
+///
+/// Conversions to and from `Size` are lossless and fail rather than clamp if the value is out of
+/// range for the target type:
+/// ```
+/// use size::{ByteCount, Size};
+/// use std::convert::TryFrom;
+///
+/// let count = ByteCount::from_gib(4);
+/// assert_eq!(Size::try_from(count), Ok(Size::from_gib(4)));
+/// assert!(Size::try_from(ByteCount::MAX).is_err());
+/// assert!(ByteCount::try_from(Size::from_kib(-1)).is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
+pub struct ByteCount {
+    bytes: u64,
+}
+
+/// Clamps a (possibly out-of-range) number of bytes to the range of a `ByteCount`.
+const fn saturate(bytes: i128) -> u64 {
+    if bytes > u64::MAX as i128 {
+        u64::MAX
+    } else if bytes < 0 {
+        0
+    } else {
+        bytes as u64
+    }
+}
+
+impl ByteCount {
+    /// Zero value (0 bytes)
+    pub const ZERO: Self = Self { bytes: 0 };
+
+    /// The largest count that can be expressed by a `ByteCount` (`u64::MAX` bytes, just under 16
+    /// EiB).
+    pub const MAX: Self = Self { bytes: u64::MAX };
+
+    /// Initialize a `ByteCount` from the provided value, in bytes. This is a constant function and
+    /// may be used in a `const` context. See [`Size::from_const()`].
+    pub const fn from_const(bytes: u64) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the number of bytes in the `ByteCount`.
+    pub const fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Initialize a `ByteCount` from the provided value, in bytes.
+    pub fn from_bytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(BYTE as i128)),
+        }
+    }
+
+    /// Express a count in kilobytes. Actual count is 10^3 \* the value.
+    pub fn from_kilobytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(KILOBYTE as i128)),
+        }
+    }
+
+    /// Express a count in megabytes. Actual count is 10^6 \* the value.
+    pub fn from_megabytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(MEGABYTE as i128)),
+        }
+    }
+
+    /// Express a count in gigabytes. Actual count is 10^9 \* the value.
+    pub fn from_gigabytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(GIGABYTE as i128)),
+        }
+    }
+
+    /// Express a count in terabytes. Actual count is 10^12 \* the value.
+    pub fn from_terabytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(TERABYTE as i128)),
+        }
+    }
+
+    /// Express a count in petabytes. Actual count is 10^15 \* the value.
+    pub fn from_petabytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(PETABYTE as i128)),
+        }
+    }
+
+    /// Express a count in exabytes. Actual count is 10^18 \* the value.
+    pub fn from_exabytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(EXABYTE as i128)),
+        }
+    }
+
+    #[inline]
+    /// Express a count in kilobytes, as a shortcut for using [`ByteCount::from_kilobytes()`].
+    pub fn from_kb<T: IntoSize>(value: T) -> Self {
+        Self::from_kilobytes(value)
+    }
+    #[inline]
+    /// Express a count in megabytes, as a shortcut for using [`ByteCount::from_megabytes()`].
+    pub fn from_mb<T: IntoSize>(value: T) -> Self {
+        Self::from_megabytes(value)
+    }
+    #[inline]
+    /// Express a count in gigabytes, as a shortcut for using [`ByteCount::from_gigabytes()`].
+    pub fn from_gb<T: IntoSize>(value: T) -> Self {
+        Self::from_gigabytes(value)
+    }
+    #[inline]
+    /// Express a count in terabytes, as a shortcut for using [`ByteCount::from_terabytes()`].
+    pub fn from_tb<T: IntoSize>(value: T) -> Self {
+        Self::from_terabytes(value)
+    }
+    #[inline]
+    /// Express a count in petabytes, as a shortcut for using [`ByteCount::from_petabytes()`].
+    pub fn from_pb<T: IntoSize>(value: T) -> Self {
+        Self::from_petabytes(value)
+    }
+    #[inline]
+    /// Express a count in exabytes, as a shortcut for using [`ByteCount::from_exabytes()`].
+    pub fn from_eb<T: IntoSize>(value: T) -> Self {
+        Self::from_exabytes(value)
+    }
+
+    /// Express a count in kibibytes. Actual count is 2^10 \* the value.
+    pub fn from_kibibytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(KIBIBYTE as i128)),
+        }
+    }
+
+    /// Express a count in mebibytes. Actual count is 2^20 \* the value.
+    pub fn from_mebibytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(MEBIBYTE as i128)),
+        }
+    }
+
+    /// Express a count in gibibytes. Actual count is 2^30 \* the value.
+    pub fn from_gibibytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(GIBIBYTE as i128)),
+        }
+    }
+
+    /// Express a count in tebibytes. Actual count is 2^40 \* the value.
+    pub fn from_tebibytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(TEBIBYTE as i128)),
+        }
+    }
+
+    /// Express a count in pebibytes. Actual count is 2^50 \* the value.
+    pub fn from_pebibytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(PEBIBYTE as i128)),
+        }
+    }
+
+    /// Express a count in exbibytes. Actual count is 2^60 \* the value.
+    pub fn from_exbibytes<T: IntoSize>(value: T) -> Self {
+        Self {
+            bytes: saturate(value.into_bytes(EXBIBYTE as i128)),
+        }
+    }
+
+    #[inline]
+    /// Express a count in kibibytes, as a shortcut for using [`ByteCount::from_kibibytes()`].
+    pub fn from_kib<T: IntoSize>(value: T) -> Self {
+        Self::from_kibibytes(value)
+    }
+    #[inline]
+    /// Express a count in mebibytes, as a shortcut for using [`ByteCount::from_mebibytes()`].
+    pub fn from_mib<T: IntoSize>(value: T) -> Self {
+        Self::from_mebibytes(value)
+    }
+    #[inline]
+    /// Express a count in gibibytes, as a shortcut for using [`ByteCount::from_gibibytes()`].
+    pub fn from_gib<T: IntoSize>(value: T) -> Self {
+        Self::from_gibibytes(value)
+    }
+    #[inline]
+    /// Express a count in tebibytes, as a shortcut for using [`ByteCount::from_tebibytes()`].
+    pub fn from_tib<T: IntoSize>(value: T) -> Self {
+        Self::from_tebibytes(value)
+    }
+    #[inline]
+    /// Express a count in pebibytes, as a shortcut for using [`ByteCount::from_pebibytes()`].
+    pub fn from_pib<T: IntoSize>(value: T) -> Self {
+        Self::from_pebibytes(value)
+    }
+    #[inline]
+    /// Express a count in exbibytes, as a shortcut for using [`ByteCount::from_exbibytes()`].
+    pub fn from_eib<T: IntoSize>(value: T) -> Self {
+        Self::from_exbibytes(value)
+    }
+
+    /// Adds two counts, returning `None` on overflow.
+    pub const fn checked_add(self, other: ByteCount) -> Option<ByteCount> {
+        match self.bytes.checked_add(other.bytes) {
+            Some(bytes) => Some(ByteCount { bytes }),
+            None => None,
+        }
+    }
+
+    /// Subtracts `other` from `self`, returning `None` if the result would be negative.
+    pub const fn checked_sub(self, other: ByteCount) -> Option<ByteCount> {
+        match self.bytes.checked_sub(other.bytes) {
+            Some(bytes) => Some(ByteCount { bytes }),
+            None => None,
+        }
+    }
+
+    /// Adds two counts, saturating at [`ByteCount::MAX`] instead of overflowing.
+    pub const fn saturating_add(self, other: ByteCount) -> ByteCount {
+        ByteCount {
+            bytes: self.bytes.saturating_add(other.bytes),
+        }
+    }
+
+    /// Subtracts `other` from `self`, saturating at [`ByteCount::ZERO`] instead of underflowing.
+    pub const fn saturating_sub(self, other: ByteCount) -> ByteCount {
+        ByteCount {
+            bytes: self.bytes.saturating_sub(other.bytes),
+        }
+    }
+
+    /// Parse a `ByteCount` from text, accepting the same input as [`Size::from_str()`].
+    ///
+    /// Negative values and values exceeding [`ByteCount::MAX`] are rejected.
+    ///
+    /// ```
+    /// use size::ByteCount;
+    ///
+    /// assert_eq!(ByteCount::from_str("15 EiB"), Ok(ByteCount::from_eib(15)));
+    /// assert!(ByteCount::from_str("-1 KiB").is_err());
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<ByteCount, ParseSizeError> {
+        s.parse()
+    }
+}
+
+impl core::fmt::Debug for ByteCount {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(fmt, "{} bytes", self.bytes())
+    }
+}
+
+impl core::str::FromStr for ByteCount {
+    type Err = ParseSizeError;
+
+    fn from_str(s: &str) -> Result<ByteCount, Self::Err> {
+        let bytes = parse_bytes(s.as_bytes())?;
+        u64::try_from(bytes)
+            .map(ByteCount::from_const)
+            .map_err(|_| ParseSizeError)
+    }
+}
+
+/* Lossless conversions to and from `Size` and the primitive integer types */
+
+impl TryFrom<Size> for ByteCount {
+    type Error = TryFromSizeError;
+
+    fn try_from(size: Size) -> Result<ByteCount, Self::Error> {
+        u64::try_from(size).map(ByteCount::from_const)
+    }
+}
+
+impl TryFrom<ByteCount> for Size {
+    type Error = TryFromSizeError;
+
+    fn try_from(count: ByteCount) -> Result<Size, Self::Error> {
+        Size::try_from(count.bytes)
+    }
+}
+
+impl From<u64> for ByteCount {
+    fn from(bytes: u64) -> ByteCount {
+        ByteCount::from_const(bytes)
+    }
+}
+
+impl From<ByteCount> for u64 {
+    fn from(count: ByteCount) -> u64 {
+        count.bytes
+    }
+}
+
+impl From<ByteCount> for u128 {
+    fn from(count: ByteCount) -> u128 {
+        count.bytes as u128
+    }
+}
+
+impl From<ByteCount> for i128 {
+    fn from(count: ByteCount) -> i128 {
+        count.bytes as i128
+    }
+}
+
+/* Arithmetic, following the semantics of the `u64` operators */
+
+impl Add<ByteCount> for ByteCount {
+    type Output = ByteCount;
+
+    fn add(self, other: ByteCount) -> Self::Output {
+        ByteCount::from_const(self.bytes + other.bytes)
+    }
+}
+
+impl Sub<ByteCount> for ByteCount {
+    type Output = ByteCount;
+
+    fn sub(self, other: ByteCount) -> Self::Output {
+        ByteCount::from_const(self.bytes - other.bytes)
+    }
+}
+
+impl AddAssign<ByteCount> for ByteCount {
+    fn add_assign(&mut self, other: ByteCount) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<ByteCount> for ByteCount {
+    fn sub_assign(&mut self, other: ByteCount) {
+        *self = *self - other;
+    }
+}
+
+impl Sum for ByteCount {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |total, count| total + count)
+    }
+}
+
+impl<'a> Sum<&'a ByteCount> for ByteCount {
+    fn sum<I: Iterator<Item = &'a ByteCount>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |total, count| total + *count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructors() {
+        assert_eq!(ByteCount::from_bytes(u64::MAX).bytes(), u64::MAX);
+        assert_eq!(ByteCount::from_kib(4_u8).bytes(), 4096);
+        assert_eq!(ByteCount::from_eib(15).bytes(), 15 << 60);
+        assert_eq!(ByteCount::from_eib(16), ByteCount::MAX);
+        assert_eq!(ByteCount::from_mb(-3), ByteCount::ZERO);
+    }
+
+    #[test]
+    fn size_conversions() {
+        assert_eq!(ByteCount::try_from(Size::MAX), Ok(ByteCount::from_const(i64::MAX as u64)));
+        assert_eq!(Size::try_from(ByteCount::from_const(i64::MAX as u64)), Ok(Size::MAX));
+        assert!(Size::try_from(ByteCount::from_const(1 << 63)).is_err());
+        assert!(ByteCount::try_from(Size::from_bytes(-1)).is_err());
+        assert_eq!(u64::from(ByteCount::from_kb(2)), 2000);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let count = ByteCount::from_eib(8) + ByteCount::from_eib(4);
+        assert_eq!(count, ByteCount::from_eib(12));
+        assert_eq!(count.checked_add(ByteCount::from_eib(4)), None);
+        assert_eq!(count.saturating_add(ByteCount::from_eib(4)), ByteCount::MAX);
+        assert_eq!(ByteCount::ZERO.checked_sub(ByteCount::from_bytes(1)), None);
+        assert_eq!(ByteCount::ZERO.saturating_sub(ByteCount::from_bytes(1)), ByteCount::ZERO);
+        assert_eq!(
+            [ByteCount::from_kib(1), ByteCount::from_kib(3)]
+                .iter()
+                .sum::<ByteCount>(),
+            ByteCount::from_kib(4)
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("12 EiB".parse(), Ok(ByteCount::from_eib(12)));
+        assert_eq!("18446744073709551615".parse(), Ok(ByteCount::MAX));
+        assert_eq!("16 EiB".parse::<ByteCount>(), Err(ParseSizeError));
+        assert_eq!("-1".parse::<ByteCount>(), Err(ParseSizeError));
+        assert_eq!("-0".parse(), Ok(ByteCount::ZERO));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn format() {
+        assert_eq!(ByteCount::from_eib(12).to_string(), "12 EiB");
+        assert_eq!(ByteCount::MAX.to_string(), "16 EiB");
+        assert_eq!(ByteCount::from_bytes(1).to_string(), "1 byte");
+        assert_eq!(ByteCount::from_bytes(1536).to_string(), "1.50 KiB");
+        assert_eq!(format!("{:?}", ByteCount::from_kib(1)), "1024 bytes");
+    }
+}
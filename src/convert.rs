@@ -0,0 +1,360 @@
+//! Conversions between [`Size`] and other types.
+//!
+//! The generic `Size` constructors (e.g. [`Size::from_kib()`]) accept any type implementing the
+//! [`IntoSize`] trait, which is implemented for all primitive numeric types as well as for
+//! [`NonZero`](core::num) integers and [`Wrapping`] values, and which may be implemented for your
+//! own types too:
+//!
+//! ```
+//! use size::{IntoSize, Size};
+//!
+//! /// A count of 4 KiB disk blocks
+//! struct Blocks(u32);
+//!
+//! impl IntoSize for Blocks {
+//!     fn into_bytes(self, multiplier: i128) -> i128 {
+//!         (self.0 as i128 * 4096).saturating_mul(multiplier)
+//!     }
+//! }
+//!
+//! assert_eq!(Size::from_bytes(Blocks(3)), Size::from_kib(12));
+//! ```
+//!
+//! The same trait bounds the scalar accepted by the `*` and `/` operators on `Size`, so the above
+//! `Blocks` may also be used to scale a size, e.g. `Size::from_kib(2) * Blocks(1)`.
+//!
+//! [`TryIntoSize`] is the fallible counterpart, used by [`Size::try_from_units()`] to report
+//! overflow instead of clamping the result.
+//!
+//! This module also provides lossless [`From`] conversions between `Size` and the primitive
+//! integer types that can always represent the same number of bytes, and [`TryFrom`] conversions
+//! for those that cannot.
+
+use crate::{Size, Unit};
+use core::convert::TryFrom;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
+
+/// A value that can be used to express a size in terms of some unit, as accepted by the generic
+/// `Size` constructors such as [`Size::from_bytes()`] or [`Size::from_mib()`].
+pub trait IntoSize {
+    /// Returns the number of bytes in `self` units of `multiplier` bytes each.
+    ///
+    /// Implementations should saturate at the bounds of an `i128` rather than overflow and should
+    /// truncate any fractional bytes toward zero. The result is further clamped to the range of the
+    /// `Size` type being constructed.
+    fn into_bytes(self, multiplier: i128) -> i128;
+
+    /// Returns `bytes` divided by `self`, as used by the `/` operator on `Size`.
+    ///
+    /// The default implementation divides `bytes` by `self.into_bytes(1)`, rounding toward zero
+    /// and panicking if the divisor is zero, as integer division does.
+    fn divide_bytes(self, bytes: i128) -> i128
+    where
+        Self: Sized,
+    {
+        match self.into_bytes(1) {
+            -1 => bytes.saturating_neg(),
+            divisor => bytes / divisor,
+        }
+    }
+}
+
+/// The fallible counterpart to [`IntoSize`], used by [`Size::try_from_units()`].
+pub trait TryIntoSize {
+    /// Returns the number of bytes in `self` units of `multiplier` bytes each, or an error if the
+    /// result cannot be represented as an `i128` (or, for floating-point values, is not a number).
+    /// Any fractional bytes are truncated toward zero.
+    fn try_into_bytes(self, multiplier: i128) -> Result<i128, TryFromSizeError>;
+}
+
+/// The error type returned when a checked conversion to or from a [`Size`] fails because the value
+/// is out of range.
+#[derive(Debug, PartialEq, Clone, Copy, Eq)]
+pub struct TryFromSizeError(pub(crate) ());
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromSizeError {}
+impl core::fmt::Display for TryFromSizeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("out of range size conversion attempted")
+    }
+}
+
+macro_rules! into_size_int {
+    ($type:ty) => {
+        impl IntoSize for $type {
+            fn into_bytes(self, multiplier: i128) -> i128 {
+                // Values that don't fit in an i128 (i.e. only u128 values) saturate, as would the
+                // product of the multiplication.
+                let value = i128::try_from(self).unwrap_or(i128::MAX);
+                value.saturating_mul(multiplier)
+            }
+        }
+
+        impl TryIntoSize for $type {
+            fn try_into_bytes(self, multiplier: i128) -> Result<i128, TryFromSizeError> {
+                i128::try_from(self)
+                    .ok()
+                    .and_then(|value| value.checked_mul(multiplier))
+                    .ok_or(TryFromSizeError(()))
+            }
+        }
+    };
+}
+
+into_size_int!(u8);
+into_size_int!(u16);
+into_size_int!(u32);
+into_size_int!(u64);
+into_size_int!(u128);
+into_size_int!(usize);
+into_size_int!(i8);
+into_size_int!(i16);
+into_size_int!(i32);
+into_size_int!(i64);
+into_size_int!(i128);
+into_size_int!(isize);
+
+#[cfg(feature = "float")]
+macro_rules! into_size_float {
+    ($type:ty) => {
+        impl IntoSize for $type {
+            fn into_bytes(self, multiplier: i128) -> i128 {
+                (self as f64 * multiplier as f64) as i128
+            }
+
+            fn divide_bytes(self, bytes: i128) -> i128 {
+                (bytes as f64 / self as f64) as i128
+            }
+        }
+
+        impl TryIntoSize for $type {
+            fn try_into_bytes(self, multiplier: i128) -> Result<i128, TryFromSizeError> {
+                let bytes = self as f64 * multiplier as f64;
+                // i128::MIN is exactly representable as an f64 but i128::MAX rounds up to 2^127.
+                if bytes.is_nan() || bytes < i128::MIN as f64 || bytes >= i128::MAX as f64 {
+                    Err(TryFromSizeError(()))
+                } else {
+                    Ok(bytes as i128)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "float")]
+into_size_float!(f32);
+#[cfg(feature = "float")]
+into_size_float!(f64);
+
+macro_rules! into_size_nonzero {
+    ($type:ty) => {
+        impl IntoSize for $type {
+            fn into_bytes(self, multiplier: i128) -> i128 {
+                self.get().into_bytes(multiplier)
+            }
+
+            fn divide_bytes(self, bytes: i128) -> i128 {
+                self.get().divide_bytes(bytes)
+            }
+        }
+
+        impl TryIntoSize for $type {
+            fn try_into_bytes(self, multiplier: i128) -> Result<i128, TryFromSizeError> {
+                self.get().try_into_bytes(multiplier)
+            }
+        }
+    };
+}
+
+into_size_nonzero!(NonZeroU8);
+into_size_nonzero!(NonZeroU16);
+into_size_nonzero!(NonZeroU32);
+into_size_nonzero!(NonZeroU64);
+into_size_nonzero!(NonZeroU128);
+into_size_nonzero!(NonZeroUsize);
+into_size_nonzero!(NonZeroI8);
+into_size_nonzero!(NonZeroI16);
+into_size_nonzero!(NonZeroI32);
+into_size_nonzero!(NonZeroI64);
+into_size_nonzero!(NonZeroI128);
+into_size_nonzero!(NonZeroIsize);
+
+impl<T: IntoSize> IntoSize for Wrapping<T> {
+    fn into_bytes(self, multiplier: i128) -> i128 {
+        self.0.into_bytes(multiplier)
+    }
+
+    fn divide_bytes(self, bytes: i128) -> i128 {
+        self.0.divide_bytes(bytes)
+    }
+}
+
+impl<T: TryIntoSize> TryIntoSize for Wrapping<T> {
+    fn try_into_bytes(self, multiplier: i128) -> Result<i128, TryFromSizeError> {
+        self.0.try_into_bytes(multiplier)
+    }
+}
+
+impl Size {
+    /// Express a size as `value` of the provided [`Unit`], returning an error instead of clamping
+    /// the result if it overflows (or, for floating-point values, if `value` is not a number).
+    ///
+    /// ```
+    /// use size::{Size, Unit};
+    ///
+    /// assert_eq!(Size::try_from_units(4_u64, Unit::GiB), Ok(Size::from_gib(4)));
+    /// assert!(Size::try_from_units(u64::MAX, Unit::KiB).is_err());
+    /// ```
+    pub fn try_from_units<T: TryIntoSize>(value: T, unit: Unit) -> Result<Size, TryFromSizeError> {
+        let bytes = value.try_into_bytes(unit.bytes_wide())?;
+        i64::try_from(bytes)
+            .map(Size::from_const)
+            .map_err(|_| TryFromSizeError(()))
+    }
+}
+
+/* Conversions between `Size` and the primitive integer types, treated as a number of bytes */
+
+macro_rules! size_from_int {
+    ($type:ty) => {
+        impl From<$type> for Size {
+            fn from(bytes: $type) -> Size {
+                Size::from_const(bytes as i64)
+            }
+        }
+    };
+}
+
+size_from_int!(u8);
+size_from_int!(u16);
+size_from_int!(u32);
+size_from_int!(i8);
+size_from_int!(i16);
+size_from_int!(i32);
+size_from_int!(i64);
+
+macro_rules! size_try_from_int {
+    ($type:ty) => {
+        impl TryFrom<$type> for Size {
+            type Error = TryFromSizeError;
+
+            fn try_from(bytes: $type) -> Result<Size, Self::Error> {
+                i64::try_from(bytes)
+                    .map(Size::from_const)
+                    .map_err(|_| TryFromSizeError(()))
+            }
+        }
+    };
+}
+
+size_try_from_int!(u64);
+size_try_from_int!(u128);
+size_try_from_int!(usize);
+size_try_from_int!(i128);
+size_try_from_int!(isize);
+
+impl From<Size> for i64 {
+    fn from(size: Size) -> i64 {
+        size.bytes()
+    }
+}
+
+impl From<Size> for i128 {
+    fn from(size: Size) -> i128 {
+        size.bytes() as i128
+    }
+}
+
+macro_rules! int_try_from_size {
+    ($type:ty) => {
+        impl TryFrom<Size> for $type {
+            type Error = TryFromSizeError;
+
+            fn try_from(size: Size) -> Result<$type, Self::Error> {
+                <$type>::try_from(size.bytes()).map_err(|_| TryFromSizeError(()))
+            }
+        }
+    };
+}
+
+int_try_from_size!(u8);
+int_try_from_size!(u16);
+int_try_from_size!(u32);
+int_try_from_size!(u64);
+int_try_from_size!(u128);
+int_try_from_size!(usize);
+int_try_from_size!(i8);
+int_try_from_size!(i16);
+int_try_from_size!(i32);
+int_try_from_size!(isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_size_extended_types() {
+        assert_eq!(Size::from_kib(NonZeroU64::new(4).unwrap()), Size::from_kib(4));
+        assert_eq!(Size::from_mib(NonZeroI32::new(-2).unwrap()), Size::from_mib(-2));
+        assert_eq!(Size::from_gib(Wrapping(3_u64)), Size::from_gib(3));
+        assert_eq!(Size::from_bytes(7_u128), Size::from_bytes(7));
+        assert_eq!(Size::from_bytes(u128::MAX), Size::MAX);
+        assert_eq!(Size::from_kib(i128::MIN), Size::MIN);
+    }
+
+    #[test]
+    fn scalar_operators() {
+        struct Blocks(u32);
+
+        impl IntoSize for Blocks {
+            fn into_bytes(self, multiplier: i128) -> i128 {
+                (self.0 as i128 * 4096).saturating_mul(multiplier)
+            }
+        }
+
+        let size = Size::from_kib(12);
+        assert_eq!(size * NonZeroU64::new(2).unwrap(), Size::from_kib(24));
+        assert_eq!(size / NonZeroU64::new(3).unwrap(), Size::from_kib(4));
+        assert_eq!(size * Wrapping(2_u8), Size::from_kib(24));
+        assert_eq!(size / Wrapping(-4_i16), Size::from_kib(-3));
+        assert_eq!(size * 2_u128, Size::from_kib(24));
+        assert_eq!(size * u128::MAX, Size::MAX);
+        assert_eq!(Size::from_bytes(2) * Blocks(1), Size::from_kib(8));
+        assert_eq!(Size::from_kib(16) / Blocks(2), Size::from_bytes(2));
+        assert_eq!(Size::MIN / -1, Size::MAX);
+
+        let mut size = Size::from_kib(1);
+        size *= NonZeroU8::new(4).unwrap();
+        size /= 2_u64;
+        assert_eq!(size, Size::from_kib(2));
+    }
+
+    #[test]
+    fn try_from_units() {
+        assert_eq!(Size::try_from_units(2_u8, Unit::KiB), Ok(Size::from_kib(2)));
+        assert_eq!(Size::try_from_units(-8_i64, Unit::EiB), Ok(Size::MIN));
+        assert_eq!(Size::try_from_units(8_i64, Unit::EiB), Err(TryFromSizeError(())));
+        assert_eq!(Size::try_from_units(u128::MAX, Unit::Byte), Err(TryFromSizeError(())));
+        assert_eq!(Size::try_from_units(Wrapping(i128::MAX), Unit::KB), Err(TryFromSizeError(())));
+    }
+
+    #[test]
+    fn primitive_conversions() {
+        assert_eq!(Size::from(42_u32), Size::from_bytes(42));
+        assert_eq!(Size::from(-42_i8), Size::from_bytes(-42));
+        assert_eq!(Size::try_from(42_u64), Ok(Size::from_bytes(42)));
+        assert_eq!(Size::try_from(u64::MAX), Err(TryFromSizeError(())));
+        assert_eq!(Size::try_from(i128::MIN), Err(TryFromSizeError(())));
+
+        assert_eq!(i64::from(Size::from_kib(1)), 1024);
+        assert_eq!(i128::from(Size::MIN), i64::MIN as i128);
+        assert_eq!(u64::try_from(Size::from_kib(1)), Ok(1024));
+        assert_eq!(usize::try_from(Size::from_kib(1)), Ok(1024));
+        assert_eq!(u64::try_from(Size::from_kib(-1)), Err(TryFromSizeError(())));
+        assert_eq!(u8::try_from(Size::from_kib(1)), Err(TryFromSizeError(())));
+    }
+}
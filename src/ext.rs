@@ -0,0 +1,81 @@
+//! The `ext` module contains the [`SizeExt`] extension trait, which makes it possible to create a
+//! [`Size`] directly from a numeric value, e.g. `64.mib()` instead of `Size::from_mib(64)`.
+//!
+//! The trait is re-exported at the crate level as `size::SizeExt` and must be in scope for its
+//! methods to be available:
+#![cfg_attr(not(all(feature = "std", feature = "float")), doc = "```ignore")]
+#![cfg_attr(all(feature = "std", feature = "float"), doc = "```")]
+//! use size::{Size, SizeExt};
+//!
+//! let buffer = 64.mib() + 512.kib();
+//! assert_eq!(buffer, Size::from_mib(64) + Size::from_kib(512));
+//!
+//! // Floating-point values are supported too, with the same semantics as `Size::from_xxx()`
+//! assert_eq!(1.5.gb(), Size::from_mb(1500));
+//! ```
+
+use crate::{IntoSize, Size};
+
+/// An extension trait for numeric types, providing shorthands for each of the
+/// `Size::from_xxx()` constructors. For example, `4.gib()` is equivalent to `Size::from_gib(4)`.
+///
+/// This trait is implemented for all types accepted by the generic `Size` constructors, i.e. all
+/// types implementing [`IntoSize`].
+pub trait SizeExt: IntoSize + Sized {
+    /// Express a size in bytes. Equivalent to [`Size::from_bytes()`].
+    fn bytes(self) -> Size {
+        Size::from_bytes(self)
+    }
+
+    /// Express a size in kilobytes. Equivalent to [`Size::from_kb()`].
+    fn kb(self) -> Size {
+        Size::from_kb(self)
+    }
+    /// Express a size in megabytes. Equivalent to [`Size::from_mb()`].
+    fn mb(self) -> Size {
+        Size::from_mb(self)
+    }
+    /// Express a size in gigabytes. Equivalent to [`Size::from_gb()`].
+    fn gb(self) -> Size {
+        Size::from_gb(self)
+    }
+    /// Express a size in terabytes. Equivalent to [`Size::from_tb()`].
+    fn tb(self) -> Size {
+        Size::from_tb(self)
+    }
+    /// Express a size in petabytes. Equivalent to [`Size::from_pb()`].
+    fn pb(self) -> Size {
+        Size::from_pb(self)
+    }
+    /// Express a size in exabytes. Equivalent to [`Size::from_eb()`].
+    fn eb(self) -> Size {
+        Size::from_eb(self)
+    }
+
+    /// Express a size in kibibytes. Equivalent to [`Size::from_kib()`].
+    fn kib(self) -> Size {
+        Size::from_kib(self)
+    }
+    /// Express a size in mebibytes. Equivalent to [`Size::from_mib()`].
+    fn mib(self) -> Size {
+        Size::from_mib(self)
+    }
+    /// Express a size in gibibytes. Equivalent to [`Size::from_gib()`].
+    fn gib(self) -> Size {
+        Size::from_gib(self)
+    }
+    /// Express a size in tebibytes. Equivalent to [`Size::from_tib()`].
+    fn tib(self) -> Size {
+        Size::from_tib(self)
+    }
+    /// Express a size in pebibytes. Equivalent to [`Size::from_pib()`].
+    fn pib(self) -> Size {
+        Size::from_pib(self)
+    }
+    /// Express a size in exbibytes. Equivalent to [`Size::from_eib()`].
+    fn eib(self) -> Size {
+        Size::from_eib(self)
+    }
+}
+
+impl<T: IntoSize> SizeExt for T {}
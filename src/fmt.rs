@@ -6,98 +6,37 @@
 //! [`SizeFormatter`] can be instantiated directly if you would like a standalone pretty-printer for
 //! raw byte sizes.
 //!
-//! The formatting-related enums in this module ([`Base`] and [`Style`]) are re-exported at the
-//! crate level as `size::Base` and `size::Style`.
+//! The formatting-related enums in this module ([`Base`], [`Style`], [`Precision`], and
+//! [`Separator`]) are re-exported at the crate level (e.g. as `size::Style`).
 
 use super::*;
-use core::fmt;
+use core::fmt::{self, Write};
 
-/// An enumeration of supported bases to use for generating textual descriptions of sizes.
-///
-/// [`Base::Base10`] is the "usual" units like "kilobyte" and "exabyte", while [`Base::Base2`] is
-/// the SI/memory units like "mebibyte" and "tebibyte", (more often referred to as "MiB" and "TiB",
-/// respectively).
-#[non_exhaustive]
-#[derive(Copy, Clone, Debug)]
-pub enum Base {
-    /// Base-2 units like "kibibyte" and "mebibyte", more often referred to via their abbreviations
-    /// ("KiB" and "MiB", respectively). Each unit is 1024 times greater than the preceding one.
-    Base2,
-    /// Base-10 units like "kilobyte" and "megabyte". Each unit is 1000 times greater than the
-    /// preceding one.
-    Base10,
-}
-
-/// A collection of units used to refer to sizes, for all supported bases.
-enum Unit {
-    /// The basic "byte" unit, used by both base-2 and base-10 styles.
-    Byte,
-    /// The base-2 "kibibyte" unit, equal to 1024 bytes.
-    Kibibyte,
-    /// The base-10 "kilobyte" unit, equal to 1000 bytes.
-    Kilobyte,
-    /// The base-2 "mebibyte" unit, equal to 1024 kibibytes.
-    Mebibyte,
-    /// The base-10 "megabyte" unit, equal to 1000 kilobytes.
-    Megabyte,
-    /// The base-2 "gibibyte" unit, equal to 1024 mebibytes.
-    Gibibyte,
-    /// The base-10 "gigabyte" unit, equal to 1000 megabytes.
-    Gigabyte,
-    /// The base-2 "tebibyte" unit, equal to 1024 gibibytes.
-    Tebibyte,
-    /// The base-10 "terabyte" unit, equal to 1000 gigabytes.
-    Terabyte,
-    /// The base-2 "pebibyte" unit, equal to 1024 tebibytes.
-    Pebibyte,
-    /// The base-10 "petabyte" unit, equal to 1000 terabytes.
-    Petabyte,
-    /// The base-2 "exbibyte" unit, equal to 1024 pebibytes.
-    Exbibyte,
-    /// The base-10 "exabyte" unit, equal to 1000 petabytes.
-    Exabyte,
-}
+pub use crate::unit::Base;
 
 impl Unit {
-    #[rustfmt::skip]
-    const fn text(&self) -> (&'static str, &'static str, &'static str, &'static str) {
-        use self::Unit::*;
-
-        match self {
-            Byte => ("byte", "Byte", "b", "B"),
-
-            Kilobyte => ("kilobyte", "Kilobyte", "kb", "KB"),
-            Megabyte => ("megabyte", "Megabyte", "mb", "MB"),
-            Gigabyte => ("gigabyte", "Gigabyte", "gb", "GB"),
-            Terabyte => ("terabyte", "Terabyte", "tb", "TB"),
-            Petabyte => ("petabyte", "Petabyte", "pb", "PB"),
-            Exabyte  => ("exabyte",  "Exabyte",  "eb", "EB"),
-
-            Kibibyte => ("kibibyte", "Kibibyte", "kib", "KiB"),
-            Mebibyte => ("mebibyte", "Mebibyte", "mib", "MiB"),
-            Gibibyte => ("gibibyte", "Gibibyte", "gib", "GiB"),
-            Pebibyte => ("pebibyte", "Pebibyte", "pib", "PiB"),
-            Tebibyte => ("tebibyte", "Tebibyte", "tib", "TiB"),
-            Exbibyte => ("exbibyte", "Exbibyte", "eib", "EiB"),
-        }
-    }
-
-    fn format(&self, fmt: &mut fmt::Formatter, bytes: u64, style: &Style) -> fmt::Result {
-        match (&style, bytes) {
+    /// Writes the name of the unit for a value of `bytes` bytes in the given `style`. Only a
+    /// single byte (which is always printed as exactly "1") is written in the singular.
+    fn format(&self, fmt: &mut fmt::Formatter, bytes: u128, style: &Style) -> fmt::Result {
+        let singular = *self == Unit::Byte && bytes == 1;
+        match (&style, singular) {
             (&Style::Default, _) => match &self {
                 &Unit::Byte => self.format(fmt, bytes, &Style::FullLowercase),
                 _ => self.format(fmt, bytes, &Style::Abbreviated),
             },
 
-            (&Style::FullLowercase, 1) => write!(fmt, " {}", self.text().0),
-            (&Style::Full, 1) => write!(fmt, " {}", self.text().1),
-            (&Style::AbbreviatedLowercase, 1) => write!(fmt, " {}", self.text().2),
-            (&Style::Abbreviated, 1) => write!(fmt, " {}", self.text().3),
+            (&Style::FullLowercase, true) => write!(fmt, "{}", self.text().0),
+            (&Style::Full, true) => write!(fmt, "{}", self.text().1),
+            (&Style::AbbreviatedLowercase, true) => write!(fmt, "{}", self.text().2),
+            (&Style::Abbreviated, true) => write!(fmt, "{}", self.text().3),
 
-            (&Style::FullLowercase, _) => write!(fmt, " {}s", self.text().0),
-            (&Style::Full, _) => write!(fmt, " {}s", self.text().1),
-            (&Style::AbbreviatedLowercase, _) => write!(fmt, " {}", self.text().2),
-            (&Style::Abbreviated, _) => write!(fmt, " {}", self.text().3),
+            (&Style::FullLowercase, false) => write!(fmt, "{}s", self.text().0),
+            (&Style::Full, false) => write!(fmt, "{}s", self.text().1),
+            (&Style::AbbreviatedLowercase, false) => write!(fmt, "{}", self.text().2),
+            (&Style::Abbreviated, false) => write!(fmt, "{}", self.text().3),
+
+            (&Style::Si, _) => write!(fmt, "{}", self.si_symbol()),
+            (&Style::Compact, _) => write!(fmt, "{}", self.text().4),
         }
     }
 }
@@ -116,8 +55,64 @@ pub enum Style {
     AbbreviatedLowercase,
     /// Full unit name style, e.g. "1024 Kilobytes" and "1.29 Gibibytes"
     Full,
-    /// Full, lowercase unit name style, e.g. "1024 kilobytes" and "1.29 gibibytes"
+    /// Full, lowercase unit name style, e.g. "1024 kilobytes" and "1.29 gibibytes". These are the
+    /// unit names specified by ISO/IEC 80000-13.
     FullLowercase,
+    /// Standards-compliant abbreviated style using the SI and ISO/IEC 80000-13 unit symbols, e.g.
+    /// "512 B", "1.02 kB" and "1.29 GiB". Unlike [`Style::Abbreviated`], the kilo prefix is a
+    /// lowercase "k" and bytes are written with the "B" symbol. Sizes formatted with
+    /// [`Base::Jedec`] are written with the IEC symbols (e.g. "1.50 KiB") in this style.
+    Si,
+    /// Compact style matching the output of GNU coreutils' `ls -h` and `du -h` (or `--si` with
+    /// [`Base::Base10`]), e.g. "512", "1.5K", "23M", and "4.0G".
+    ///
+    /// Sizes are rounded up (unless configured otherwise with [`SizeFormatter::with_rounding()`])
+    /// and written with at most one digit after the decimal point (only below 10 units) and a
+    /// single-letter unit suffix (none for bytes). The configured precision, separator, and unit
+    /// options are ignored.
+    Compact,
+}
+
+impl RoundingMode {
+    /// Returns the equivalent rounding of the magnitude of a size: one of `Ceil`, `Floor`,
+    /// `Nearest`, or `NearestEven`. Rounding toward either infinity is flipped for negative sizes.
+    fn for_magnitude(self, negative: bool) -> RoundingMode {
+        match (self, negative) {
+            (RoundingMode::Ceil, true) => RoundingMode::Floor,
+            (RoundingMode::Floor, true) => RoundingMode::Ceil,
+            (RoundingMode::Truncate, _) => RoundingMode::Floor,
+            (RoundingMode::Strict, _) => RoundingMode::NearestEven,
+            (rounding, _) => rounding,
+        }
+    }
+}
+
+/// The separator written between the number and the unit of a formatted size, as configured by
+/// [`SizeFormatter::with_separator()`].
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Separator {
+    /// No separator at all, e.g. "1.5GiB"
+    None,
+    /// A regular space (U+0020), e.g. "1.5 GiB". This is the default.
+    Space,
+    /// A no-break space (U+00A0), which prevents the number and unit from being split across lines
+    /// (e.g. in HTML output).
+    NoBreakSpace,
+    /// A narrow no-break space (U+202F), as recommended by SI typography.
+    NarrowNoBreakSpace,
+}
+
+impl Separator {
+    /// Returns the text of the separator.
+    const fn as_str(&self) -> &'static str {
+        match self {
+            Separator::None => "",
+            Separator::Space => " ",
+            Separator::NoBreakSpace => "\u{a0}",
+            Separator::NarrowNoBreakSpace => "\u{202f}",
+        }
+    }
 }
 
 // Backwards-compatibility associated constants to mimic `Style` variants to enable compilation of
@@ -142,17 +137,43 @@ impl Style {
     pub const FullLowerCase: Style = Style::FullLowercase;
 }
 
-impl std::fmt::Display for Size {
+/// The precision with which the numeric part of a formatted size is written, as configured by
+/// [`SizeFormatter::with_precision()`].
+///
+/// Sizes that are printed as a whole number of bytes are never written with a fractional part.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Precision {
+    /// A fixed number of digits after the decimal point, e.g. "1.50 MiB" and "12.50 MiB" for
+    /// `Decimals(2)`. This is the same as configuring a scale with
+    /// [`SizeFormatter::with_scale()`].
+    Decimals(usize),
+    /// A fixed number of significant figures, e.g. "1.23 MiB", "12.3 MiB", and "123 MiB" for
+    /// `Significant(3)`. The whole part of a size is never rounded, so "1234 KiB" keeps all four
+    /// digits. A value of zero is treated as one.
+    Significant(usize),
+}
+
+impl fmt::Display for Size {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        write!(fmt, "{}", self.format())
+        fmt::Display::fmt(&self.format(), fmt)
     }
 }
 
 mod sealed {
-    pub trait FormatterSize {}
+    pub trait FormatterSize {
+        /// Whether the sizes formatted can exceed the exabyte range. Sizes that can't (at most 16
+        /// EiB) are written in whole exabytes, as they always have been, while wide sizes get the
+        /// same fractional digits in exabytes as in every other unit.
+        const WIDE: bool = false;
+    }
 
     impl FormatterSize for () {}
-    impl<'a> FormatterSize for &'a crate::Size {}
+    impl FormatterSize for &crate::Size {}
+    impl FormatterSize for &crate::ByteCount {}
+    impl FormatterSize for &crate::WideSize {
+        const WIDE: bool = true;
+    }
 }
 
 /// A standalone size formatter that is configured via the builder pattern (via the various `.with_`
@@ -163,7 +184,14 @@ mod sealed {
 /// approach, but it may come in handy when you have many sizes and all need to be formatted in an
 /// identical and manually-specified fashion.
 ///
-/// ```
+/// By default, sizes are written with two digits after the decimal point below 10 units, one digit
+/// below 100 units, and none above that, so that the number never has more than four digits (e.g.
+/// "1.23 KiB", "12.3 KiB", and "1023 KiB"). This takes rounding into account: a size that would be
+/// rounded up to a full unit is written in terms of that unit instead ("1.00 MiB", not "1024
+/// KiB"). See [`with_threshold()`](Self::with_threshold) to switch units earlier.
+///
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+#[cfg_attr(feature = "std", doc = "```")]
 /// use size::{Base, Size, SizeFormatter, Style};
 ///
 /// let formatter = SizeFormatter::new()
@@ -199,7 +227,14 @@ pub struct SizeFormatter<T: sealed::FormatterSize = ()> {
     size: T,
     base: Base,
     style: Style,
-    scale: Option<usize>,
+    separator: Separator,
+    precision: Option<Precision>,
+    trim_zeros: bool,
+    rounding: Option<RoundingMode>,
+    threshold: Option<u16>,
+    unit: Option<Unit>,
+    min_unit: Option<Unit>,
+    max_unit: Option<Unit>,
 }
 
 impl Default for SizeFormatter<()> {
@@ -227,12 +262,54 @@ impl<F: Fn(&mut fmt::Formatter) -> fmt::Result> fmt::Display for FmtRenderer<F>
     }
 }
 
+/// A [`fmt::Write`] sink that discards its input, counting the number of characters written so
+/// that formatted text can be padded to a given width without allocating.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
 impl<T: sealed::FormatterSize> SizeFormatter<T> {
+    /// Creates a formatter for `size` with the default configuration.
+    const fn with_defaults(size: T) -> Self {
+        SizeFormatter {
+            size,
+            base: DEFAULT_BASE,
+            style: DEFAULT_STYLE,
+            separator: DEFAULT_SEPARATOR,
+            precision: DEFAULT_PRECISION,
+            trim_zeros: DEFAULT_TRIM_ZEROS,
+            rounding: DEFAULT_ROUNDING,
+            threshold: DEFAULT_THRESHOLD,
+            unit: DEFAULT_UNIT,
+            min_unit: DEFAULT_MIN_UNIT,
+            max_unit: DEFAULT_MAX_UNIT,
+        }
+    }
+
     /// Specify the base of the units to be used when generating the textual description of the
     /// `Size`.
     ///
     /// This lets users choose between "standard" base-10 units like "KB" and "MB" or the improved
-    /// SI base-2 units like "KiB" and "MiB". See [`Base`] for more information.
+    /// SI base-2 units like "KiB" and "MiB" (optionally labeled "KB" and "MB" with [`Base::Jedec`]),
+    /// or to pick between the two for each value with [`Base::Auto`]. See [`Base`] for more
+    /// information.
+    ///
+    #[cfg_attr(not(feature = "std"), doc = "```ignore")]
+    #[cfg_attr(feature = "std", doc = "```")]
+    /// use size::{Base, Size};
+    ///
+    /// let auto = |size: Size| size.format().with_base(Base::Auto).to_string();
+    /// assert_eq!(auto(Size::from_bytes(4096)), "4.00 KiB");
+    /// assert_eq!(auto(Size::from_gib(8)), "8.00 GiB");
+    /// assert_eq!(auto(Size::from_tb(4)), "4.00 TB");
+    /// assert_eq!(auto(Size::from_bytes(1500)), "1.50 KB");
+    /// assert_eq!(auto(Size::from_mib(1000)), "1.05 GB");
+    /// ```
     pub fn with_base(self, base: Base) -> Self {
         Self { base, ..self }
     }
@@ -244,11 +321,27 @@ impl<T: sealed::FormatterSize> SizeFormatter<T> {
         Self { style, ..self }
     }
 
+    /// Specify the separator written between the number and the unit of a formatted size.
+    ///
+    /// See [`Separator`] for more information.
+    ///
+    /// ```
+    /// use size::{Separator, Size};
+    ///
+    /// let size = Size::from_mib(1536);
+    /// assert_eq!(size.format().with_separator(Separator::None).to_string(), "1.50GiB");
+    /// assert_eq!(size.format().with_separator(Separator::NoBreakSpace).to_string(), "1.50\u{a0}GiB");
+    /// ```
+    pub fn with_separator(self, separator: Separator) -> Self {
+        Self { separator, ..self }
+    }
+
     /// Specify the scale/precision of the formatted sizes.
     ///
     /// Sets the number of digits after the decimal point for formatted sizes. A value of `Some(0)`
     /// prints whole numbers only while a value of `None` uses the default formatting which uses a
-    /// different scale/precision for sizes depending on the chosen unit.
+    /// different scale/precision for sizes depending on the chosen unit. `Some(n)` is equivalent to
+    /// [`with_precision(Precision::Decimals(n))`](Self::with_precision).
     ///
     /// # Examples
     ///
@@ -263,7 +356,8 @@ impl<T: sealed::FormatterSize> SizeFormatter<T> {
     /// ```
     ///
     /// Sizes that are printed as a whole number of bytes do not have a scale:
-    /// ```
+    #[cfg_attr(not(feature = "std"), doc = "```ignore")]
+    #[cfg_attr(feature = "std", doc = "```")]
     /// use size::SizeFormatter;
     ///
     /// let bytes = SizeFormatter::new()
@@ -272,64 +366,353 @@ impl<T: sealed::FormatterSize> SizeFormatter<T> {
     /// assert_eq!(&bytes, "123 bytes");
     /// ```
     pub fn with_scale(self, scale: Option<usize>) -> Self {
-        Self { scale, ..self }
-    }
-
-    /// Formats the provided `bytes` value with the configured [`self.base`], [`self.style`], and
-    /// [`self.scale`].
-    fn inner_fmt(&self, fmt: &mut fmt::Formatter, bytes: i64) -> fmt::Result {
-        let bytes = match bytes {
-            x @ 0..=i64::MAX => x as u64,
-            y => {
-                write!(fmt, "-")?;
-
-                // The absolute magnitude of T::MIN for a signed number is one more than
-                // that of T::MAX, meaning T::MIN.abs() will panic.
-                match y.checked_abs() {
-                    Some(abs) => abs as u64,
-                    None => i64::MAX as u64,
-                }
-            }
+        Self {
+            precision: scale.map(Precision::Decimals),
+            ..self
+        }
+    }
+
+    /// Specify the precision of the formatted sizes as either a number of digits after the decimal
+    /// point or a number of significant figures.
+    ///
+    /// See [`Precision`] for more information and [`with_scale()`](Self::with_scale) to restore the
+    /// default behavior.
+    ///
+    /// ```
+    /// use size::{Precision, Size};
+    ///
+    /// let sig = |size: Size| size.format().with_precision(Precision::Significant(3)).to_string();
+    /// assert_eq!(sig(Size::from_bytes(1_290_000)), "1.23 MiB");
+    /// assert_eq!(sig(Size::from_bytes(12_900_000)), "12.3 MiB");
+    /// assert_eq!(sig(Size::from_bytes(129_000_000)), "123 MiB");
+    /// ```
+    pub fn with_precision(self, precision: Precision) -> Self {
+        Self {
+            precision: Some(precision),
+            ..self
+        }
+    }
+
+    /// Specify how the last digit of formatted sizes is rounded.
+    ///
+    /// See [`RoundingMode`] for more information.
+    ///
+    /// ```
+    /// use size::{RoundingMode, Size};
+    ///
+    /// let size = Size::from_bytes(1_025);
+    /// assert_eq!(size.format().to_string(), "1.00 KiB");
+    /// assert_eq!(size.format().with_rounding(RoundingMode::Ceil).to_string(), "1.01 KiB");
+    /// ```
+    pub fn with_rounding(self, rounding: RoundingMode) -> Self {
+        Self {
+            rounding: Some(rounding),
+            ..self
+        }
+    }
+
+    /// Specify the number of a unit at which sizes are instead written in terms of the next larger
+    /// unit. By default this is a full unit (1024 or 1000), but e.g. a threshold of 1000 for base-2
+    /// units keeps the number of digits before the decimal point to at most three ("1000 KiB" is
+    /// written as "0.98 MiB").
+    ///
+    /// The threshold only applies to units: sizes below one kilobyte (or kibibyte) are always
+    /// written in bytes. A threshold greater than the number of units in the next unit of the
+    /// configured [`Base`] has no effect, as sizes are always written in the next unit once they
+    /// reach a whole one. Sizes are written in the next unit whenever rounding would otherwise
+    /// carry them to the threshold (e.g. 1023.9 KiB is written as "1.00 MiB" and not "1024 KiB").
+    ///
+    /// ```
+    /// use size::Size;
+    ///
+    /// let size = Size::from_kib(1000);
+    /// assert_eq!(size.to_string(), "1000 KiB");
+    /// assert_eq!(size.format().with_threshold(1000).to_string(), "0.98 MiB");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is zero.
+    pub fn with_threshold(self, threshold: u16) -> Self {
+        assert!(threshold != 0, "size formatting threshold must be non-zero");
+        Self {
+            threshold: Some(threshold),
+            ..self
+        }
+    }
+
+    /// Specify whether trailing zeros after the decimal point are written (the default) or omitted,
+    /// e.g. "1.5 MiB" rather than "1.50 MiB". The decimal point itself is omitted if no non-zero
+    /// digits follow it.
+    ///
+    /// ```
+    /// use size::{Precision, Size};
+    ///
+    /// let formatted = Size::from_kib(1536)
+    ///     .format()
+    ///     .with_precision(Precision::Decimals(2))
+    ///     .with_trailing_zeros(false)
+    ///     .to_string();
+    /// assert_eq!(formatted, "1.5 MiB");
+    /// ```
+    pub fn with_trailing_zeros(self, trailing_zeros: bool) -> Self {
+        Self {
+            trim_zeros: !trailing_zeros,
+            ..self
+        }
+    }
+
+    /// Format all sizes in terms of the provided [`Unit`] rather than automatically choosing the
+    /// most appropriate unit for each size, e.g. to print a column of sizes in a report.
+    ///
+    /// The configured [`Base`] is ignored in favor of the base of `unit`, except that with
+    /// [`Base::Jedec`] a base-10 unit stands for the base-2 unit it labels (e.g. [`Unit::KB`] for
+    /// 1024 bytes). Unless a different scale is configured via [`with_scale()`](Self::with_scale),
+    /// sizes are printed with two digits after the decimal point.
+    ///
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    /// use size::{SizeFormatter, Unit};
+    ///
+    /// let formatter = SizeFormatter::new().with_unit(Unit::MiB);
+    /// assert_eq!(formatter.format(12_345), "0.01 MiB");
+    /// assert_eq!(formatter.format(20 << 30), "20480.00 MiB");
+    /// ```
+    pub fn with_unit(self, unit: Unit) -> Self {
+        Self {
+            unit: Some(unit),
+            ..self
+        }
+    }
+
+    /// Specify the smallest unit that sizes may be automatically formatted in, e.g. to print
+    /// "0.30 KiB" instead of "312 bytes".
+    ///
+    /// Sizes below one `unit` are printed as a fraction of that unit, with the same scale that
+    /// would be used for a size of a few `unit`s. If `unit` is not of the configured [`Base`], the
+    /// smallest unit of that base that is at least as large as `unit` is used instead. With
+    /// [`Base::Jedec`], base-10 units stand for the base-2 units they label. This has no effect if
+    /// a fixed unit was chosen via [`with_unit()`](Self::with_unit).
+    ///
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    /// use size::{SizeFormatter, Unit};
+    ///
+    /// let formatter = SizeFormatter::new().with_min_unit(Unit::KiB);
+    /// assert_eq!(formatter.format(312), "0.30 KiB");
+    /// assert_eq!(formatter.format(3 << 20), "3.00 MiB");
+    /// ```
+    pub fn with_min_unit(self, unit: Unit) -> Self {
+        Self {
+            min_unit: Some(unit),
+            ..self
+        }
+    }
+
+    /// Specify the largest unit that sizes may be automatically formatted in, e.g. to print
+    /// "2048 GiB" instead of "2.00 TiB".
+    ///
+    /// Larger sizes are printed as a whole number of `unit`s (unless a different scale is
+    /// configured via [`with_scale()`](Self::with_scale)). If `unit` is not of the configured
+    /// [`Base`], the largest unit of that base that is no larger than `unit` is used instead. With
+    /// [`Base::Jedec`], base-10 units stand for the base-2 units they label. If the maximum unit is
+    /// smaller than the minimum unit, the maximum unit wins. This has no effect if a fixed unit was
+    /// chosen via [`with_unit()`](Self::with_unit).
+    ///
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    /// use size::{SizeFormatter, Unit};
+    ///
+    /// let formatter = SizeFormatter::new().with_max_unit(Unit::GiB);
+    /// assert_eq!(formatter.format(2 << 40), "2048 GiB");
+    /// assert_eq!(formatter.format(3 << 20), "3.00 MiB");
+    /// ```
+    pub fn with_max_unit(self, unit: Unit) -> Self {
+        Self {
+            max_unit: Some(unit),
+            ..self
+        }
+    }
+
+    /// Formats the provided `bytes` value with the configured [`self.base`], [`self.style`],
+    /// [`self.precision`], and [`self.unit`] (or [`self.min_unit`] and [`self.max_unit`]).
+    fn inner_fmt(&self, fmt: &mut fmt::Formatter, bytes: i128) -> fmt::Result {
+        // Unlike `abs()`, this can't overflow for `i128::MIN`.
+        self.fmt_padded(fmt, bytes < 0, bytes.unsigned_abs())
+    }
+
+    /// Formats a size given as a sign and a number of bytes, shared by the signed [`Size`] and
+    /// [`WideSize`] and the unsigned [`ByteCount`].
+    ///
+    /// The width, fill, alignment, and sign flags of `fmt` are honored, and its precision (if any)
+    /// overrides the configured precision as a number of digits after the decimal point. Sizes are
+    /// aligned to the left by default, the same as strings.
+    fn fmt_padded(&self, fmt: &mut fmt::Formatter, negative: bool, bytes: u128) -> fmt::Result {
+        let precision = fmt.precision().map(Precision::Decimals).or(self.precision);
+        let sign = match (negative, fmt.sign_plus()) {
+            (true, _) => "-",
+            (false, true) => "+",
+            (false, false) => "",
         };
+        let text = FmtRenderer::new(|fmt: &mut fmt::Formatter| {
+            fmt.write_str(sign)?;
+            self.fmt_magnitude(fmt, bytes, precision, negative)
+        });
 
-        let rule = match self.base {
-            Base::Base2 => match BASE2_RULES.binary_search_by_key(&bytes, |rule| rule.less_than) {
-                Ok(index) => &BASE2_RULES[index + 1],
-                Err(index) => &BASE2_RULES[index],
-            },
-            Base::Base10 => {
-                match BASE10_RULES.binary_search_by_key(&bytes, |rule| rule.less_than) {
-                    Ok(index) => &BASE10_RULES[index + 1],
-                    Err(index) => &BASE10_RULES[index],
-                }
+        let width = match fmt.width() {
+            Some(width) => width,
+            None => return write!(fmt, "{}", text),
+        };
+        let mut counter = CharCounter(0);
+        write!(counter, "{}", text)?;
+        let padding = width.saturating_sub(counter.0);
+        let (before, after) = match fmt.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Left) | None => (0, padding),
+        };
+
+        let fill = fmt.fill();
+        for _ in 0..before {
+            fmt.write_char(fill)?;
+        }
+        write!(fmt, "{}", text)?;
+        for _ in 0..after {
+            fmt.write_char(fill)?;
+        }
+        Ok(())
+    }
+
+    /// Formats an unsigned number of bytes (of a size that is `negative` or not) with the given
+    /// precision, ignoring any formatter flags.
+    fn fmt_magnitude(
+        &self,
+        fmt: &mut fmt::Formatter,
+        bytes: u128,
+        precision: Option<Precision>,
+        negative: bool,
+    ) -> fmt::Result {
+        let base = self.base.resolve(bytes);
+        if let Style::Compact = self.style {
+            let rounding = self.rounding.unwrap_or(RoundingMode::Ceil);
+            return fmt_compact(fmt, bytes, base, rounding.for_magnitude(negative));
+        }
+
+        let rounding = self.rounding.unwrap_or(RoundingMode::NearestEven);
+        let rounding = rounding.for_magnitude(negative);
+        let (unit, mantissa) = match self.unit.map(|unit| self.measured_unit(unit)) {
+            Some(unit) => {
+                let rule = FormatRule {
+                    less_than: u128::MAX,
+                    unit,
+                    scale: FIXED_UNIT_SCALE,
+                };
+                (unit, rule.mantissa(bytes, precision, rounding))
             }
+            None => self.auto_scale(bytes, base, precision, rounding),
         };
 
-        (rule.formatter)(fmt, bytes, self.scale)?;
-        rule.unit.format(fmt, bytes, &self.style)?;
+        // JEDEC-style sizes are measured in base-2 units but labeled with the base-10 names, except
+        // in the standards-compliant style, which always uses the IEC symbols for base-2 units.
+        let label = match (self.base, unit.base(), self.style) {
+            (_, _, Style::Si) => unit,
+            (Base::Jedec, Some(Base::Base2), _) => unit.counterpart(),
+            _ => unit,
+        };
+
+        mantissa.write(fmt, self.trim_zeros)?;
+        fmt.write_str(self.separator.as_str())?;
+        label.format(fmt, bytes, &self.style)?;
 
         Ok(())
     }
+
+    /// Returns the unit that sizes labeled as `unit` are measured in: JEDEC-style sizes are labeled
+    /// with the base-10 names but measured in the base-2 units (e.g. "KB" is 1024 bytes).
+    fn measured_unit(&self, unit: Unit) -> Unit {
+        match (self.base, unit.base()) {
+            (Base::Jedec, Some(Base::Base10)) => unit.counterpart(),
+            _ => unit,
+        }
+    }
+
+    /// Picks the unit (and default scale) for `bytes` from the rules for the (resolved) `base` and
+    /// returns it along with the rounded mantissa.
+    ///
+    /// A rule is only kept if the rounded mantissa stays within its range: e.g. 1023.9 KiB would
+    /// be rounded to "1024 KiB" and is instead written as "1.00 MiB" by the next rule.
+    fn auto_scale(
+        &self,
+        bytes: u128,
+        base: Base,
+        precision: Option<Precision>,
+        rounding: RoundingMode,
+    ) -> (Unit, Mantissa) {
+        let (rules, radix): (&[FormatRule], u128) = match base {
+            Base::Base2 | Base::Jedec | Base::Auto => (&BASE2_RULES, 1024),
+            Base::Base10 => (&BASE10_RULES, 1000),
+        };
+
+        // Each unit is only used for sizes below `threshold` of that unit, while plain bytes are
+        // always used up to a full unit.
+        let threshold = self.threshold.map_or(radix, |t| (t as u128).min(radix));
+        let limit = |rule: &FormatRule| match rule.unit {
+            Unit::Byte => rule.less_than,
+            unit => rule.less_than.min(threshold * unit.bytes_wide() as u128),
+        };
+
+        // The rules are sorted by unit, so the bounds are also found by binary search: the first
+        // rule for the smallest permitted unit and the last for the largest.
+        let end = match self.max_unit.map(|unit| self.measured_unit(unit)) {
+            Some(max) => rules.partition_point(|rule| rule.unit.bytes_wide() <= max.bytes_wide()),
+            None => rules.len(),
+        };
+        let end = end.max(1);
+
+        // The last rule catches everything up to and including `u128::MAX`.
+        let mut index = rules.partition_point(|rule| limit(rule) <= bytes);
+        if let Some(min) = self.min_unit.map(|unit| self.measured_unit(unit)) {
+            index =
+                index.max(rules.partition_point(|rule| rule.unit.bytes_wide() < min.bytes_wide()));
+        }
+        index = index.min(end - 1);
+
+        loop {
+            let rule = &rules[index];
+            let mantissa = match rule.unit {
+                Unit::Exabyte | Unit::Exbibyte if !T::WIDE => {
+                    let whole = FormatRule { scale: 0, ..*rule };
+                    whole.mantissa(bytes, precision, rounding)
+                }
+                _ => rule.mantissa(bytes, precision, rounding),
+            };
+            let rolled_over = rule.unit != Unit::Byte
+                && mantissa.whole >= limit(rule) / rule.unit.bytes_wide() as u128;
+            if !rolled_over || index + 1 == end {
+                return (rule.unit, mantissa);
+            }
+            index += 1;
+        }
+    }
 }
 
 impl SizeFormatter<()> {
     /// Create a new `SizeFormatter` that can be used to repeatedly format a number of file sizes
     /// according to its configured options.
     pub const fn new() -> SizeFormatter<()> {
-        SizeFormatter {
-            size: (),
-            base: DEFAULT_BASE,
-            style: DEFAULT_STYLE,
-            scale: DEFAULT_SCALE,
-        }
+        SizeFormatter::with_defaults(())
     }
 
     /// Formats a provided size in bytes as a string, per the configuration of the current
     /// `SizeFormatter` instance.
-    pub fn format(&self, bytes: i64) -> String {
-        format!(
+    ///
+    /// This requires the `alloc` feature; without it, format a [`Size`] with [`Size::format()`]
+    /// and write the result to any [`core::fmt::Write`] implementation instead.
+    #[cfg(feature = "alloc")]
+    pub fn format(&self, bytes: i64) -> alloc::string::String {
+        alloc::format!(
             "{}",
-            FmtRenderer::new(|fmt: &mut fmt::Formatter| { self.inner_fmt(fmt, bytes) })
+            FmtRenderer::new(|fmt: &mut fmt::Formatter| { self.inner_fmt(fmt, bytes as i128) })
         )
     }
 }
@@ -350,7 +733,8 @@ impl SizeFormatter<()> {
 /// [`SizeFormatter`] instead of using `Size::format()`.
 ///
 /// Example:
-/// ```
+#[cfg_attr(not(all(feature = "std", feature = "float")), doc = "```ignore")]
+#[cfg_attr(all(feature = "std", feature = "float"), doc = "```")]
 /// use size::{Base, Size, Style};
 ///
 /// let size = Size::from_mib(1.907349);
@@ -368,11 +752,74 @@ impl SizeFormatter<()> {
 pub type FormattableSize<'a> = SizeFormatter<&'a Size>;
 
 impl fmt::Display for FormattableSize<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner_fmt(f, self.size.bytes() as i128)
+    }
+}
+
+/// Result of [`ByteCount::format()`], the unsigned counterpart to [`FormattableSize`].
+pub type FormattableByteCount<'a> = SizeFormatter<&'a ByteCount>;
+
+impl fmt::Display for FormattableByteCount<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_padded(f, false, self.size.bytes() as u128)
+    }
+}
+
+impl fmt::Display for ByteCount {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.format(), fmt)
+    }
+}
+
+impl ByteCount {
+    /// Returns a textual representation of the [`ByteCount`] for display purposes, configurable in
+    /// the same way as [`Size::format()`].
+    ///
+    /// ```
+    /// use size::{Base, ByteCount};
+    ///
+    /// let count = ByteCount::from_bytes(u64::MAX);
+    /// assert_eq!(count.to_string(), "16 EiB");
+    /// assert_eq!(count.format().with_base(Base::Base10).to_string(), "18 EB");
+    /// ```
+    pub fn format(&self) -> FormattableByteCount<'_> {
+        FormattableByteCount::with_defaults(self)
+    }
+}
+
+/// Result of [`WideSize::format()`], the 128-bit counterpart to [`FormattableSize`].
+pub type FormattableWideSize<'a> = SizeFormatter<&'a WideSize>;
+
+impl fmt::Display for FormattableWideSize<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.inner_fmt(f, self.size.bytes())
     }
 }
 
+impl fmt::Display for WideSize {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.format(), fmt)
+    }
+}
+
+impl WideSize {
+    /// Returns a textual representation of the [`WideSize`] for display purposes, configurable in
+    /// the same way as [`Size::format()`].
+    ///
+    #[cfg_attr(not(all(feature = "std", feature = "float")), doc = "```ignore")]
+    #[cfg_attr(all(feature = "std", feature = "float"), doc = "```")]
+    /// use size::{Base, WideSize};
+    ///
+    /// let size = WideSize::from_zib(1.5);
+    /// assert_eq!(size.to_string(), "1.50 ZiB");
+    /// assert_eq!(size.format().with_base(Base::Base10).to_string(), "1.77 ZB");
+    /// ```
+    pub fn format(&self) -> FormattableWideSize<'_> {
+        FormattableWideSize::with_defaults(self)
+    }
+}
+
 impl Size {
     /// Returns a textual representation of the [`Size`] for display purposes.
     ///
@@ -380,7 +827,8 @@ impl Size {
     /// [`Base::Base10`]), and the style used to express the determined unit (see [`Style`]).
     ///
     /// Example:
-    /// ```
+    #[cfg_attr(not(all(feature = "std", feature = "float")), doc = "```ignore")]
+    #[cfg_attr(all(feature = "std", feature = "float"), doc = "```")]
     /// use size::{Base, Size, Style};
     ///
     /// let size = Size::from_mib(1.907349);
@@ -396,258 +844,788 @@ impl Size {
     /// It is not necessary to call `.to_string()` if you are passing the formatted size to a
     /// `format!()` macro or similar (e.g. `println!` and friends), as the result implements
     /// [`Display`](std::fmt::Display) and will resolve to the same text.
-    pub fn format(&self) -> FormattableSize {
-        FormattableSize {
-            size: self,
-            base: DEFAULT_BASE,
-            style: DEFAULT_STYLE,
-            scale: DEFAULT_SCALE,
-        }
+    pub fn format(&self) -> FormattableSize<'_> {
+        FormattableSize::with_defaults(self)
     }
 }
 
+/// A range of sizes (up to but excluding `less_than` bytes) that are all formatted in terms of the
+/// same `unit` with (unless overridden) the same number of digits after the decimal point.
 struct FormatRule {
-    less_than: u64,
-    formatter: fn(&mut fmt::Formatter, bytes: u64, scale: Option<usize>) -> fmt::Result,
+    less_than: u128,
     unit: Unit,
+    scale: usize,
+}
+
+/// The default number of digits after the decimal point when formatting in a fixed unit (see
+/// [`SizeFormatter::with_unit()`]), so that all sizes line up regardless of their magnitude.
+const FIXED_UNIT_SCALE: usize = 2;
+
+/// The number of fractional digits after which the exact quotient of any size and any unit has
+/// terminated (a `u128` divided by at most 2^100 or 10^30), so that all further digits are zero.
+const MAX_FRACTION_DIGITS: usize = 100;
+
+/// The numeric part of a formatted size: a whole number followed by `scale` digits after the
+/// decimal point, of which only the first `generated` are stored (all others are zero).
+struct Mantissa {
+    whole: u128,
+    digits: [u8; MAX_FRACTION_DIGITS],
+    generated: usize,
+    scale: usize,
 }
 
-const BASE10_RULES: [FormatRule; 17] = [
-    FormatRule {
-        less_than: KILOBYTE as u64,
-        formatter: |fmt, bytes, _| write!(fmt, "{0:.0}", bytes),
-        unit: Unit::Byte,
-    },
-    FormatRule {
-        less_than: 10 * KILOBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (KILOBYTE as f64), scale.unwrap_or(2))
-        },
-        unit: Unit::Kilobyte,
-    },
-    FormatRule {
-        less_than: 100 * KILOBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (KILOBYTE as f64), scale.unwrap_or(1))
-        },
-        unit: Unit::Kilobyte,
-    },
-    FormatRule {
-        less_than: MEGABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (KILOBYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Kilobyte,
-    },
-    FormatRule {
-        less_than: 10 * MEGABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (MEGABYTE as f64), scale.unwrap_or(2))
-        },
-        unit: Unit::Megabyte,
-    },
-    FormatRule {
-        less_than: 100 * MEGABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (MEGABYTE as f64), scale.unwrap_or(1))
-        },
-        unit: Unit::Megabyte,
-    },
-    FormatRule {
-        less_than: GIGABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (MEGABYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Megabyte,
-    },
-    FormatRule {
-        less_than: 10 * GIGABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (GIGABYTE as f64), scale.unwrap_or(2))
-        },
-        unit: Unit::Gigabyte,
-    },
-    FormatRule {
-        less_than: 100 * GIGABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (GIGABYTE as f64), scale.unwrap_or(1))
-        },
-        unit: Unit::Gigabyte,
-    },
-    FormatRule {
-        less_than: TERABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (GIGABYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Gigabyte,
-    },
-    FormatRule {
-        less_than: 10 * TERABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (TERABYTE as f64), scale.unwrap_or(2))
-        },
-        unit: Unit::Terabyte,
-    },
-    FormatRule {
-        less_than: 100 * TERABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (TERABYTE as f64), scale.unwrap_or(1))
-        },
-        unit: Unit::Terabyte,
-    },
-    FormatRule {
-        less_than: PETABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (TERABYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Terabyte,
-    },
-    FormatRule {
-        less_than: 10 * PETABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (PETABYTE as f64), scale.unwrap_or(2))
-        },
-        unit: Unit::Petabyte,
-    },
-    FormatRule {
-        less_than: 100 * PETABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (PETABYTE as f64), scale.unwrap_or(1))
-        },
-        unit: Unit::Petabyte,
-    },
-    FormatRule {
-        less_than: EXABYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (PETABYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Petabyte,
-    },
-    FormatRule {
-        less_than: u64::MAX,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (EXABYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Exabyte,
-    },
+impl FormatRule {
+    /// Expresses `bytes` in terms of `self.unit` with the requested `precision` (or the rule's
+    /// default scale), without any floating-point math.
+    ///
+    /// The digits of the quotient are generated exactly by long division and the result is then
+    /// rounded per `rounding`, which applies to the magnitude of the size.
+    fn mantissa(
+        &self,
+        bytes: u128,
+        precision: Option<Precision>,
+        rounding: RoundingMode,
+    ) -> Mantissa {
+        let mut digits = [0_u8; MAX_FRACTION_DIGITS];
+
+        // Sizes that are printed as a whole number of bytes do not have a scale.
+        if self.unit == Unit::Byte {
+            return Mantissa {
+                whole: bytes,
+                digits,
+                generated: 0,
+                scale: 0,
+            };
+        }
+
+        let divisor = self.unit.bytes_wide() as u128;
+        let mut whole = bytes / divisor;
+        let mut remainder = bytes % divisor;
+
+        let mut scale = match precision {
+            None => self.scale,
+            Some(Precision::Decimals(scale)) => scale,
+            Some(Precision::Significant(figures)) if whole > 0 => {
+                figures.saturating_sub(decimal_digits(whole))
+            }
+            Some(Precision::Significant(figures)) => match leading_zeros(remainder, divisor) {
+                Some(zeros) => zeros + figures.max(1),
+                None => 0,
+            },
+        };
+
+        let mut generated = scale.min(MAX_FRACTION_DIGITS);
+        for digit in digits[..generated].iter_mut() {
+            // `remainder` is less than `divisor` (at most 2^100), so this can't overflow.
+            remainder *= 10;
+            *digit = (remainder / divisor) as u8;
+            remainder %= divisor;
+        }
+
+        // Round the magnitude, carrying into the preceding digits (and the whole part) as needed.
+        let last_is_odd = match generated {
+            0 => whole % 2 == 1,
+            n => digits[n - 1] % 2 == 1,
+        };
+        let round_up = match (rounding, (2 * remainder).cmp(&divisor)) {
+            (RoundingMode::Ceil, _) => remainder > 0,
+            (RoundingMode::Floor, _) => false,
+            (_, core::cmp::Ordering::Greater) => true,
+            (RoundingMode::Nearest, core::cmp::Ordering::Equal) => true,
+            (_, core::cmp::Ordering::Equal) => last_is_odd,
+            (_, core::cmp::Ordering::Less) => false,
+        };
+        if round_up {
+            let mut carry = true;
+            for digit in digits[..generated].iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    carry = false;
+                    break;
+                }
+            }
+            if carry {
+                whole += 1;
+            }
+        }
+
+        // Rounding up may have carried into a new leading digit (e.g. 9.996 to 10.00), leaving one
+        // digit too many. The digits after a carry are all zeros, so the last can simply be dropped.
+        if let Some(Precision::Significant(figures)) = precision {
+            let significant = match whole {
+                0 => generated - digits[..generated].iter().take_while(|d| **d == 0).count(),
+                _ => decimal_digits(whole) + scale,
+            };
+            if significant > figures.max(1) && scale > 0 {
+                scale -= 1;
+                generated = generated.min(scale);
+            }
+        }
+
+        Mantissa {
+            whole,
+            digits,
+            generated,
+            scale,
+        }
+    }
+}
+
+impl Mantissa {
+    /// Writes the mantissa, omitting any trailing zeros after the decimal point if `trim_zeros` is
+    /// set.
+    fn write(&self, fmt: &mut fmt::Formatter, trim_zeros: bool) -> fmt::Result {
+        let digits = &self.digits[..self.generated];
+        let (shown, padded) = match trim_zeros {
+            true => {
+                let shown = digits.iter().rposition(|d| *d != 0).map_or(0, |i| i + 1);
+                (shown, shown)
+            }
+            false => (self.generated, self.scale),
+        };
+
+        write!(fmt, "{}", self.whole)?;
+        if padded > 0 {
+            fmt.write_char('.')?;
+            for digit in &digits[..shown] {
+                fmt.write_char((b'0' + digit) as char)?;
+            }
+            for _ in shown..padded {
+                fmt.write_char('0')?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes `bytes` in the [`Style::Compact`] style, replicating the `human_readable()` function of
+/// GNU coreutils with the `human_autoscale` option used by `ls -h`/`du -h`. `RoundingMode::Ceil`,
+/// `Floor`, and `NearestEven` match its `human_ceiling`, `human_floor`, and `human_round_to_nearest`
+/// options, respectively.
+fn fmt_compact(
+    fmt: &mut fmt::Formatter,
+    bytes: u128,
+    base: Base,
+    rounding_mode: RoundingMode,
+) -> fmt::Result {
+    let radix: u128 = match base {
+        Base::Base2 | Base::Jedec | Base::Auto => 1024,
+        Base::Base10 => 1000,
+    };
+    let max_exponent = Unit::iter(base).count() - 1;
+
+    if bytes < radix {
+        return write!(fmt, "{}", bytes);
+    }
+
+    // Divide by the radix until the whole part is less than one unit, tracking the first digit
+    // after the decimal point (`tenths`) and whether the discarded remainder was zero (0), less
+    // than half (1), exactly half (2), or more than half (3) of a tenth.
+    let mut amount = bytes;
+    let mut tenths = 0;
+    let mut rounding = 0;
+    let mut exponent = 0;
+    while exponent == 0 || (amount >= radix && exponent < max_exponent) {
+        let r10 = (amount % radix) * 10 + tenths;
+        let r2 = (r10 % radix) * 2 + (rounding >> 1);
+        amount /= radix;
+        tenths = r10 / radix;
+        rounding = match r2 < radix {
+            true => (r2 + rounding != 0) as u128,
+            false => 2 + (r2 + rounding > radix) as u128,
+        };
+        exponent += 1;
+    }
+
+    let mut unit = Unit::iter(base).nth(exponent).unwrap_or(Unit::Byte);
+    if amount < 10 {
+        let round_up = match rounding_mode {
+            RoundingMode::Ceil => rounding > 0,
+            RoundingMode::Floor => false,
+            RoundingMode::Nearest => rounding >= 2,
+            _ => rounding + (tenths & 1) > 2,
+        };
+        if round_up {
+            tenths += 1;
+            rounding = 0;
+            if tenths == 10 {
+                amount += 1;
+                tenths = 0;
+            }
+        }
+        if amount < 10 {
+            return write!(fmt, "{}.{}{}", amount, tenths, unit.text().4);
+        }
+    }
+
+    let round_up = match rounding_mode {
+        RoundingMode::Ceil => tenths + rounding > 0,
+        RoundingMode::Floor => false,
+        RoundingMode::Nearest => tenths >= 5,
+        _ => tenths + (rounding + (amount & 1) > 0) as u128 > 5,
+    };
+    if round_up {
+        amount += 1;
+        if amount == radix && exponent < max_exponent {
+            unit = Unit::iter(base).nth(exponent + 1).unwrap_or(unit);
+            return write!(fmt, "1.0{}", unit.text().4);
+        }
+    }
+    write!(fmt, "{}{}", amount, unit.text().4)
+}
+
+/// Returns the number of digits in the decimal representation of `value`.
+fn decimal_digits(mut value: u128) -> usize {
+    let mut digits = 1;
+    while value >= 10 {
+        value /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+/// Returns the number of zeros between the decimal point and the first non-zero digit of the
+/// fraction `remainder / divisor`, or `None` if the fraction is zero.
+fn leading_zeros(mut remainder: u128, divisor: u128) -> Option<usize> {
+    if remainder == 0 {
+        return None;
+    }
+
+    let mut zeros = 0;
+    // `remainder` is less than `divisor` (at most 2^100), so this can't overflow.
+    while remainder * 10 < divisor {
+        remainder *= 10;
+        zeros += 1;
+    }
+    Some(zeros)
+}
+
+// Sizes in exabytes/exbibytes were long the largest that could be formatted and have always been
+// printed as whole numbers (e.g. "8 EiB" for `Size::MAX`); all larger units follow the same pattern
+// as the smaller ones.
+
+#[rustfmt::skip]
+const BASE10_RULES: [FormatRule; 31] = [
+    FormatRule { less_than: KILOBYTE as u128, unit: Unit::Byte, scale: 0 },
+    FormatRule { less_than: 10 * KILOBYTE as u128, unit: Unit::Kilobyte, scale: 2 },
+    FormatRule { less_than: 100 * KILOBYTE as u128, unit: Unit::Kilobyte, scale: 1 },
+    FormatRule { less_than: MEGABYTE as u128, unit: Unit::Kilobyte, scale: 0 },
+    FormatRule { less_than: 10 * MEGABYTE as u128, unit: Unit::Megabyte, scale: 2 },
+    FormatRule { less_than: 100 * MEGABYTE as u128, unit: Unit::Megabyte, scale: 1 },
+    FormatRule { less_than: GIGABYTE as u128, unit: Unit::Megabyte, scale: 0 },
+    FormatRule { less_than: 10 * GIGABYTE as u128, unit: Unit::Gigabyte, scale: 2 },
+    FormatRule { less_than: 100 * GIGABYTE as u128, unit: Unit::Gigabyte, scale: 1 },
+    FormatRule { less_than: TERABYTE as u128, unit: Unit::Gigabyte, scale: 0 },
+    FormatRule { less_than: 10 * TERABYTE as u128, unit: Unit::Terabyte, scale: 2 },
+    FormatRule { less_than: 100 * TERABYTE as u128, unit: Unit::Terabyte, scale: 1 },
+    FormatRule { less_than: PETABYTE as u128, unit: Unit::Terabyte, scale: 0 },
+    FormatRule { less_than: 10 * PETABYTE as u128, unit: Unit::Petabyte, scale: 2 },
+    FormatRule { less_than: 100 * PETABYTE as u128, unit: Unit::Petabyte, scale: 1 },
+    FormatRule { less_than: EXABYTE as u128, unit: Unit::Petabyte, scale: 0 },
+    FormatRule { less_than: 10 * EXABYTE as u128, unit: Unit::Exabyte, scale: 2 },
+    FormatRule { less_than: 100 * EXABYTE as u128, unit: Unit::Exabyte, scale: 1 },
+    FormatRule { less_than: ZETTABYTE as u128, unit: Unit::Exabyte, scale: 0 },
+    FormatRule { less_than: 10 * ZETTABYTE as u128, unit: Unit::Zettabyte, scale: 2 },
+    FormatRule { less_than: 100 * ZETTABYTE as u128, unit: Unit::Zettabyte, scale: 1 },
+    FormatRule { less_than: YOTTABYTE as u128, unit: Unit::Zettabyte, scale: 0 },
+    FormatRule { less_than: 10 * YOTTABYTE as u128, unit: Unit::Yottabyte, scale: 2 },
+    FormatRule { less_than: 100 * YOTTABYTE as u128, unit: Unit::Yottabyte, scale: 1 },
+    FormatRule { less_than: RONNABYTE as u128, unit: Unit::Yottabyte, scale: 0 },
+    FormatRule { less_than: 10 * RONNABYTE as u128, unit: Unit::Ronnabyte, scale: 2 },
+    FormatRule { less_than: 100 * RONNABYTE as u128, unit: Unit::Ronnabyte, scale: 1 },
+    FormatRule { less_than: QUETTABYTE as u128, unit: Unit::Ronnabyte, scale: 0 },
+    FormatRule { less_than: 10 * QUETTABYTE as u128, unit: Unit::Quettabyte, scale: 2 },
+    FormatRule { less_than: 100 * QUETTABYTE as u128, unit: Unit::Quettabyte, scale: 1 },
+    FormatRule { less_than: u128::MAX, unit: Unit::Quettabyte, scale: 0 },
 ];
 
-const BASE2_RULES: [FormatRule; 17] = [
-    FormatRule {
-        less_than: KIBIBYTE as u64,
-        formatter: |fmt, bytes, _| write!(fmt, "{0:.0}", bytes),
-        unit: Unit::Byte,
-    },
-    FormatRule {
-        less_than: 10 * KIBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (KIBIBYTE as f64), scale.unwrap_or(2))
-        },
-        unit: Unit::Kibibyte,
-    },
-    FormatRule {
-        less_than: 100 * KIBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (KIBIBYTE as f64), scale.unwrap_or(1))
-        },
-        unit: Unit::Kibibyte,
-    },
-    FormatRule {
-        less_than: MEBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (KIBIBYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Kibibyte,
-    },
-    FormatRule {
-        less_than: 10 * MEBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (MEBIBYTE as f64), scale.unwrap_or(2))
-        },
-        unit: Unit::Mebibyte,
-    },
-    FormatRule {
-        less_than: 100 * MEBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (MEBIBYTE as f64), scale.unwrap_or(1))
-        },
-        unit: Unit::Mebibyte,
-    },
-    FormatRule {
-        less_than: GIBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (MEBIBYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Mebibyte,
-    },
-    FormatRule {
-        less_than: 10 * GIBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (GIBIBYTE as f64), scale.unwrap_or(2))
-        },
-        unit: Unit::Gibibyte,
-    },
-    FormatRule {
-        less_than: 100 * GIBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (GIBIBYTE as f64), scale.unwrap_or(1))
-        },
-        unit: Unit::Gibibyte,
-    },
-    FormatRule {
-        less_than: TEBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (GIBIBYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Gibibyte,
-    },
-    FormatRule {
-        less_than: 10 * TEBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (TEBIBYTE as f64), scale.unwrap_or(2))
-        },
-        unit: Unit::Tebibyte,
-    },
-    FormatRule {
-        less_than: 100 * TEBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (TEBIBYTE as f64), scale.unwrap_or(1))
-        },
-        unit: Unit::Tebibyte,
-    },
-    FormatRule {
-        less_than: PEBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (TEBIBYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Tebibyte,
-    },
-    FormatRule {
-        less_than: 10 * PEBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (PEBIBYTE as f64), scale.unwrap_or(2))
-        },
-        unit: Unit::Pebibyte,
-    },
-    FormatRule {
-        less_than: 100 * PEBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (PEBIBYTE as f64), scale.unwrap_or(1))
-        },
-        unit: Unit::Pebibyte,
-    },
-    FormatRule {
-        less_than: EXBIBYTE as u64,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (PEBIBYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Pebibyte,
-    },
-    FormatRule {
-        less_than: u64::MAX,
-        formatter: |fmt, bytes, scale| {
-            write!(fmt, "{0:.1$}", bytes as f64 / (EXBIBYTE as f64), scale.unwrap_or(0))
-        },
-        unit: Unit::Exbibyte,
-    },
+#[rustfmt::skip]
+const BASE2_RULES: [FormatRule; 31] = [
+    FormatRule { less_than: KIBIBYTE as u128, unit: Unit::Byte, scale: 0 },
+    FormatRule { less_than: 10 * KIBIBYTE as u128, unit: Unit::Kibibyte, scale: 2 },
+    FormatRule { less_than: 100 * KIBIBYTE as u128, unit: Unit::Kibibyte, scale: 1 },
+    FormatRule { less_than: MEBIBYTE as u128, unit: Unit::Kibibyte, scale: 0 },
+    FormatRule { less_than: 10 * MEBIBYTE as u128, unit: Unit::Mebibyte, scale: 2 },
+    FormatRule { less_than: 100 * MEBIBYTE as u128, unit: Unit::Mebibyte, scale: 1 },
+    FormatRule { less_than: GIBIBYTE as u128, unit: Unit::Mebibyte, scale: 0 },
+    FormatRule { less_than: 10 * GIBIBYTE as u128, unit: Unit::Gibibyte, scale: 2 },
+    FormatRule { less_than: 100 * GIBIBYTE as u128, unit: Unit::Gibibyte, scale: 1 },
+    FormatRule { less_than: TEBIBYTE as u128, unit: Unit::Gibibyte, scale: 0 },
+    FormatRule { less_than: 10 * TEBIBYTE as u128, unit: Unit::Tebibyte, scale: 2 },
+    FormatRule { less_than: 100 * TEBIBYTE as u128, unit: Unit::Tebibyte, scale: 1 },
+    FormatRule { less_than: PEBIBYTE as u128, unit: Unit::Tebibyte, scale: 0 },
+    FormatRule { less_than: 10 * PEBIBYTE as u128, unit: Unit::Pebibyte, scale: 2 },
+    FormatRule { less_than: 100 * PEBIBYTE as u128, unit: Unit::Pebibyte, scale: 1 },
+    FormatRule { less_than: EXBIBYTE as u128, unit: Unit::Pebibyte, scale: 0 },
+    FormatRule { less_than: 10 * EXBIBYTE as u128, unit: Unit::Exbibyte, scale: 2 },
+    FormatRule { less_than: 100 * EXBIBYTE as u128, unit: Unit::Exbibyte, scale: 1 },
+    FormatRule { less_than: ZEBIBYTE as u128, unit: Unit::Exbibyte, scale: 0 },
+    FormatRule { less_than: 10 * ZEBIBYTE as u128, unit: Unit::Zebibyte, scale: 2 },
+    FormatRule { less_than: 100 * ZEBIBYTE as u128, unit: Unit::Zebibyte, scale: 1 },
+    FormatRule { less_than: YOBIBYTE as u128, unit: Unit::Zebibyte, scale: 0 },
+    FormatRule { less_than: 10 * YOBIBYTE as u128, unit: Unit::Yobibyte, scale: 2 },
+    FormatRule { less_than: 100 * YOBIBYTE as u128, unit: Unit::Yobibyte, scale: 1 },
+    FormatRule { less_than: ROBIBYTE as u128, unit: Unit::Yobibyte, scale: 0 },
+    FormatRule { less_than: 10 * ROBIBYTE as u128, unit: Unit::Robibyte, scale: 2 },
+    FormatRule { less_than: 100 * ROBIBYTE as u128, unit: Unit::Robibyte, scale: 1 },
+    FormatRule { less_than: QUEBIBYTE as u128, unit: Unit::Robibyte, scale: 0 },
+    FormatRule { less_than: 10 * QUEBIBYTE as u128, unit: Unit::Quebibyte, scale: 2 },
+    FormatRule { less_than: 100 * QUEBIBYTE as u128, unit: Unit::Quebibyte, scale: 1 },
+    FormatRule { less_than: u128::MAX, unit: Unit::Quebibyte, scale: 0 },
 ];
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A fixed-capacity `core::fmt::Write` sink, to format sizes without allocating.
+    pub(crate) struct Buffer {
+        bytes: [u8; 64],
+        len: usize,
+    }
+
+    impl Buffer {
+        pub(crate) fn format(args: fmt::Arguments) -> Buffer {
+            let mut buffer = Buffer {
+                bytes: [0; 64],
+                len: 0,
+            };
+            fmt::write(&mut buffer, args).unwrap();
+            buffer
+        }
+
+        pub(crate) fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    impl fmt::Debug for Buffer {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            fmt::Debug::fmt(self.as_str(), f)
+        }
+    }
+
+    impl PartialEq<&str> for Buffer {
+        fn eq(&self, other: &&str) -> bool {
+            self.as_str() == *other
+        }
+    }
+
+    /// Formats `value` with its `Display` impl into a [`Buffer`].
+    pub(crate) fn fmt(value: impl fmt::Display) -> Buffer {
+        Buffer::format(format_args!("{}", value))
+    }
+
+    #[test]
+    fn fixed_unit() {
+        let fixed = |bytes: i64, unit: Unit| fmt(Size::from_bytes(bytes).format().with_unit(unit));
+        assert_eq!(fixed(12_345, Unit::MiB), "0.01 MiB");
+        assert_eq!(fixed(20 << 30, Unit::MiB), "20480.00 MiB");
+        assert_eq!(fixed(-1536, Unit::KiB), "-1.50 KiB");
+        assert_eq!(fixed(1_500_000, Unit::KB), "1500.00 KB");
+        assert_eq!(fixed(1, Unit::MiB), "0.00 MiB");
+        assert_eq!(fixed(1 << 20, Unit::Byte), "1048576 bytes");
+        assert_eq!(fixed(1, Unit::Byte), "1 byte");
+
+        let size = Size::from_kib(2);
+        let formatted = size
+            .format()
+            .with_unit(Unit::KiB)
+            .with_style(Style::Full)
+            .with_scale(Some(0));
+        assert_eq!(fmt(formatted), "2 Kibibytes");
+    }
+
+    #[test]
+    fn unit_bounds() {
+        let bounded = |bytes: i64, min: Unit, max: Unit, base: Base| {
+            let size = Size::from_bytes(bytes);
+            fmt(size
+                .format()
+                .with_base(base)
+                .with_min_unit(min)
+                .with_max_unit(max))
+        };
+        assert_eq!(bounded(312, Unit::KiB, Unit::GiB, Base::Base2), "0.30 KiB");
+        assert_eq!(bounded(0, Unit::KiB, Unit::GiB, Base::Base2), "0.00 KiB");
+        assert_eq!(bounded(2 << 40, Unit::KiB, Unit::GiB, Base::Base2), "2048 GiB");
+        assert_eq!(bounded(-(2 << 40), Unit::KiB, Unit::GiB, Base::Base2), "-2048 GiB");
+        assert_eq!(bounded(3 << 20, Unit::KiB, Unit::GiB, Base::Base2), "3.00 MiB");
+        assert_eq!(bounded(312, Unit::KB, Unit::GB, Base::Base10), "0.31 KB");
+        assert_eq!(bounded(2_000_000_000_000, Unit::KB, Unit::GB, Base::Base10), "2000 GB");
+        assert_eq!(
+            bounded(Size::MAX.bytes(), Unit::Byte, Unit::Byte, Base::Base2),
+            "9223372036854775807 bytes"
+        );
+
+        // Units of the other base are rounded toward the configured base's units.
+        assert_eq!(bounded(312, Unit::KB, Unit::GB, Base::Base2), "0.30 KiB");
+        assert_eq!(bounded(2 << 40, Unit::KB, Unit::TB, Base::Base2), "2048 GiB");
+
+        let size = Size::from_tib(2);
+        let formatted = size.format().with_max_unit(Unit::GiB).with_scale(Some(1));
+        assert_eq!(fmt(formatted), "2048.0 GiB");
+    }
+
+    #[test]
+    fn precision() {
+        let format = |bytes: i64, precision: Precision, trailing_zeros: bool| {
+            let size = Size::from_bytes(bytes);
+            fmt(size
+                .format()
+                .with_precision(precision)
+                .with_trailing_zeros(trailing_zeros))
+        };
+        let sig = |bytes: i64, figures: usize| format(bytes, Precision::Significant(figures), true);
+
+        assert_eq!(sig(1_290_000, 3), "1.23 MiB");
+        assert_eq!(sig(12_900_000, 3), "12.3 MiB");
+        assert_eq!(sig(129_000_000, 3), "123 MiB");
+        assert_eq!(sig(1_000 << 20, 2), "1000 MiB");
+        assert_eq!(sig(1536, 1), "2 KiB");
+        assert_eq!(sig(1536, 0), "2 KiB");
+        assert_eq!(sig(1536, 5), "1.5000 KiB");
+        assert_eq!(sig(-1536, 2), "-1.5 KiB");
+        assert_eq!(sig(512, 3), "512 bytes");
+        // Rounding must not add an extra significant figure.
+        assert_eq!(sig(10_235, 3), "10.0 KiB");
+        assert_eq!(sig(1_048_575, 3), "1.00 MiB");
+
+        // Fractions of the smallest permitted unit
+        let in_kib = |bytes: i64| {
+            let size = Size::from_bytes(bytes);
+            fmt(size
+                .format()
+                .with_unit(Unit::KiB)
+                .with_precision(Precision::Significant(2)))
+        };
+        assert_eq!(in_kib(10), "0.0098 KiB");
+        assert_eq!(in_kib(1023), "1.0 KiB");
+        assert_eq!(in_kib(0), "0 KiB");
+
+        let decimals =
+            |bytes: i64, places: usize| format(bytes, Precision::Decimals(places), false);
+        assert_eq!(decimals(1536 << 10, 2), "1.5 MiB");
+        assert_eq!(decimals(1 << 20, 2), "1 MiB");
+        assert_eq!(decimals(1_049_000, 3), "1 MiB");
+        assert_eq!(decimals(1_049_700, 3), "1.001 MiB");
+        assert_eq!(format(1536 << 10, Precision::Decimals(2), true), "1.50 MiB");
+
+        // Trimming also applies to the default precision.
+        let size = Size::from_kib(1536);
+        assert_eq!(fmt(size.format().with_trailing_zeros(false)), "1.5 MiB");
+    }
+
+    #[test]
+    fn flags() {
+        let size = Size::from_bytes(1536);
+        assert_eq!(Buffer::format(format_args!("{:>10}|", size)), "  1.50 KiB|");
+        assert_eq!(Buffer::format(format_args!("{:10}|", size)), "1.50 KiB  |");
+        assert_eq!(Buffer::format(format_args!("{:*^11}|", size)), "*1.50 KiB**|");
+        assert_eq!(Buffer::format(format_args!("{:3}|", size)), "1.50 KiB|");
+        assert_eq!(Buffer::format(format_args!("{:.1}", size)), "1.5 KiB");
+        assert_eq!(Buffer::format(format_args!("{:.0}", size)), "2 KiB");
+        assert_eq!(Buffer::format(format_args!("{:+}", size)), "+1.50 KiB");
+        assert_eq!(Buffer::format(format_args!("{:+}", Size::from_bytes(-1536))), "-1.50 KiB");
+        assert_eq!(Buffer::format(format_args!("{:>+12.3}|", size)), "  +1.500 KiB|");
+        assert_eq!(
+            Buffer::format(format_args!("{:>12}|", Size::from_bytes(-1536))),
+            "   -1.50 KiB|"
+        );
+        assert_eq!(Buffer::format(format_args!("{:.2}", Size::from_bytes(12))), "12 bytes");
+
+        // The format string's precision overrides the configured precision.
+        let formatted = size.format().with_precision(Precision::Significant(1));
+        assert_eq!(fmt(&formatted), "2 KiB");
+        assert_eq!(Buffer::format(format_args!("{:<8.3}|", formatted)), "1.500 KiB|");
+
+        let count = ByteCount::from_kib(2);
+        assert_eq!(Buffer::format(format_args!("{:>+10}|", count)), " +2.00 KiB|");
+        let wide = WideSize::from_zib(3);
+        assert_eq!(Buffer::format(format_args!("{:_<10.1}|", wide)), "3.0 ZiB___|");
+    }
+
+    #[test]
+    fn separator() {
+        let separated = |bytes: i64, separator: Separator, style: Style| {
+            let size = Size::from_bytes(bytes);
+            fmt(size.format().with_separator(separator).with_style(style))
+        };
+        assert_eq!(separated(1536 << 20, Separator::None, Style::Default), "1.50GiB");
+        assert_eq!(separated(1536 << 20, Separator::Space, Style::Default), "1.50 GiB");
+        assert_eq!(separated(1, Separator::None, Style::Default), "1byte");
+        assert_eq!(
+            separated(1536 << 20, Separator::NoBreakSpace, Style::Full),
+            "1.50\u{a0}Gibibytes"
+        );
+        assert_eq!(
+            separated(-1536 << 20, Separator::NarrowNoBreakSpace, Style::Default),
+            "-1.50\u{202f}GiB"
+        );
+
+        // Padding counts the separator as a single character.
+        let size = Size::from_bytes(1536);
+        let formatted = size.format().with_separator(Separator::NarrowNoBreakSpace);
+        assert_eq!(Buffer::format(format_args!("{:>9}|", formatted)), " 1.50\u{202f}KiB|");
+    }
+
+    #[test]
+    fn compact() {
+        // Expected values were generated with `ls -lh` and `ls -l --si` from GNU coreutils 9.1.
+        let expected = [
+            (0_i64, "0", "0"),
+            (1, "1", "1"),
+            (512, "512", "512"),
+            (1_023, "1023", "1.1k"),
+            (1_024, "1.0K", "1.1k"),
+            (1_025, "1.1K", "1.1k"),
+            (1_536, "1.5K", "1.6k"),
+            (9_950, "9.8K", "10k"),
+            (9_999, "9.8K", "10k"),
+            (10_000, "9.8K", "10k"),
+            (10_239, "10K", "11k"),
+            (10_240, "10K", "11k"),
+            (10_241, "11K", "11k"),
+            (102_400, "100K", "103k"),
+            (999_999, "977K", "1.0M"),
+            (1_000_000, "977K", "1.0M"),
+            (1_000_001, "977K", "1.1M"),
+            (1_048_063, "1.0M", "1.1M"),
+            (1_048_575, "1.0M", "1.1M"),
+            (1_048_576, "1.0M", "1.1M"),
+            (1_048_577, "1.1M", "1.1M"),
+            (10_485_759, "10M", "11M"),
+            (10_485_760, "10M", "11M"),
+            (24_117_248, "23M", "25M"),
+            (123_456_789, "118M", "124M"),
+            (4_294_967_296, "4.0G", "4.3G"),
+            (4_294_967_297, "4.1G", "4.3G"),
+            (1_099_511_627_775, "1.0T", "1.1T"),
+        ];
+        for (bytes, base2, base10) in expected.iter() {
+            let size = Size::from_bytes(*bytes);
+            let formatted = size.format().with_style(Style::Compact);
+            assert_eq!(fmt(&formatted), *base2);
+            assert_eq!(fmt(formatted.with_base(Base::Base10)), *base10);
+        }
+
+        let size = Size::from_bytes(-1536);
+        let formatted = size.format().with_style(Style::Compact);
+        assert_eq!(Buffer::format(format_args!("{:>6}", formatted)), " -1.5K");
+        assert_eq!(fmt(Size::MAX.format().with_style(Style::Compact)), "8.0E");
+    }
+
+    #[test]
+    fn rounding_modes() {
+        let rounded = |bytes: i64, rounding: RoundingMode| {
+            fmt(Size::from_bytes(bytes).format().with_rounding(rounding))
+        };
+
+        // 1.0009765625 KiB
+        assert_eq!(rounded(1025, RoundingMode::Ceil), "1.01 KiB");
+        assert_eq!(rounded(1025, RoundingMode::Floor), "1.00 KiB");
+        assert_eq!(rounded(1025, RoundingMode::Nearest), "1.00 KiB");
+        assert_eq!(rounded(1025, RoundingMode::NearestEven), "1.00 KiB");
+        assert_eq!(rounded(-1025, RoundingMode::Ceil), "-1.00 KiB");
+        assert_eq!(rounded(-1025, RoundingMode::Floor), "-1.01 KiB");
+        assert_eq!(rounded(1535, RoundingMode::Truncate), "1.49 KiB");
+        assert_eq!(rounded(-1535, RoundingMode::Truncate), "-1.49 KiB");
+        assert_eq!(rounded(1152, RoundingMode::Strict), "1.12 KiB");
+
+        // 1.125 KiB is a tie at two decimal places.
+        assert_eq!(rounded(1152, RoundingMode::Nearest), "1.13 KiB");
+        assert_eq!(rounded(1152, RoundingMode::NearestEven), "1.12 KiB");
+        assert_eq!(rounded(-1152, RoundingMode::Nearest), "-1.13 KiB");
+
+        // Rounding never changes an exact value.
+        assert_eq!(rounded(1536, RoundingMode::Ceil), "1.50 KiB");
+        assert_eq!(rounded(1536, RoundingMode::Floor), "1.50 KiB");
+        assert_eq!(rounded(10_239, RoundingMode::Floor), "9.99 KiB");
+
+        let compact = |bytes: i64, rounding: RoundingMode| {
+            let size = Size::from_bytes(bytes);
+            fmt(size
+                .format()
+                .with_style(Style::Compact)
+                .with_rounding(rounding))
+        };
+        assert_eq!(compact(1025, RoundingMode::Ceil), "1.1K");
+        assert_eq!(compact(1025, RoundingMode::Floor), "1.0K");
+        assert_eq!(compact(1075, RoundingMode::Nearest), "1.0K");
+        assert_eq!(compact(1280, RoundingMode::NearestEven), "1.2K");
+        assert_eq!(compact(1280, RoundingMode::Nearest), "1.3K");
+        assert_eq!(compact(1792, RoundingMode::NearestEven), "1.8K");
+        assert_eq!(compact(1126, RoundingMode::NearestEven), "1.1K");
+        assert_eq!(compact(1_048_575, RoundingMode::Floor), "1023K");
+        assert_eq!(compact(1_048_575, RoundingMode::NearestEven), "1.0M");
+        assert_eq!(compact(20_992, RoundingMode::NearestEven), "20K");
+        assert_eq!(compact(20_992, RoundingMode::Nearest), "21K");
+        assert_eq!(compact(22_016, RoundingMode::NearestEven), "22K");
+        assert_eq!(compact(-1025, RoundingMode::Ceil), "-1.0K");
+    }
+
+    #[test]
+    fn rollover() {
+        let format = |bytes: i64| fmt(Size::from_bytes(bytes));
+        assert_eq!(format(10_239), "10.0 KiB");
+        assert_eq!(format(102_399), "100 KiB");
+        assert_eq!(format(1_048_575), "1.00 MiB");
+        assert_eq!(format(1_048_063), "1023 KiB");
+        assert_eq!(format(-1_048_575), "-1.00 MiB");
+
+        let size = Size::from_bytes(999_999);
+        assert_eq!(fmt(size.format().with_base(Base::Base10)), "1.00 MB");
+
+        // Rounding up can roll over where rounding to the nearest value doesn't.
+        let size = Size::from_bytes(1_047_553);
+        assert_eq!(fmt(size), "1023 KiB");
+        assert_eq!(fmt(size.format().with_rounding(RoundingMode::Ceil)), "1.00 MiB");
+
+        // Sizes never roll over past the maximum unit.
+        let size = Size::from_bytes(1_048_575);
+        assert_eq!(fmt(size.format().with_max_unit(Unit::KiB)), "1024 KiB");
+
+        let threshold = |bytes: i64, threshold: u16| {
+            fmt(Size::from_bytes(bytes).format().with_threshold(threshold))
+        };
+        assert_eq!(threshold(999, 1000), "999 bytes");
+        assert_eq!(threshold(1000, 1000), "1000 bytes");
+        assert_eq!(threshold(1024, 1000), "1.00 KiB");
+        assert_eq!(threshold(1000 << 10, 1000), "0.98 MiB");
+        assert_eq!(threshold(1_023_487, 1000), "999 KiB");
+        assert_eq!(threshold(1_023_488, 1000), "0.98 MiB");
+        assert_eq!(threshold(60 << 10, 50), "0.06 MiB");
+        assert_eq!(threshold(49 << 10, 50), "49.0 KiB");
+        assert_eq!(threshold(2000, u16::MAX), "1.95 KiB");
+        // The threshold doesn't apply to plain bytes.
+        assert_eq!(threshold(50, 50), "50 bytes");
+        assert_eq!(threshold(1, 1), "1 byte");
+        assert_eq!(threshold(1 << 10, 1), "0.00 MiB");
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold must be non-zero")]
+    fn zero_threshold() {
+        let _ = Size::from_bytes(1).format().with_threshold(0);
+    }
+
+    #[test]
+    fn jedec() {
+        let jedec = |bytes: i64, style: Style| {
+            fmt(Size::from_bytes(bytes)
+                .format()
+                .with_base(Base::Jedec)
+                .with_style(style))
+        };
+        assert_eq!(jedec(1536, Style::Default), "1.50 KB");
+        assert_eq!(jedec(1000, Style::Default), "1000 bytes");
+        assert_eq!(jedec(3 << 30, Style::Full), "3.00 Gigabytes");
+        assert_eq!(jedec(3 << 30, Style::AbbreviatedLowercase), "3.00 gb");
+        assert_eq!(jedec(1_048_575, Style::Default), "1.00 MB");
+        assert_eq!(jedec(1536, Style::Compact), "1.5K");
+        assert_eq!(jedec(Size::MAX.bytes(), Style::Default), "8 EB");
+
+        let size = Size::from_mib(1536);
+        let formatted = size.format().with_base(Base::Legacy).with_unit(Unit::MiB);
+        assert_eq!(fmt(formatted), "1536.00 MB");
+
+        // Base-10 units name the base-2 units they label.
+        let formatted = |unit: Unit| {
+            fmt(Size::from_bytes(1536)
+                .format()
+                .with_base(Base::Jedec)
+                .with_unit(unit))
+        };
+        assert_eq!(formatted(Unit::KB), "1.50 KB");
+        assert_eq!(formatted(Unit::KiB), "1.50 KB");
+        let bounded = |bytes: i64, min: Unit, max: Unit| {
+            let size = Size::from_bytes(bytes);
+            let formatted = size
+                .format()
+                .with_base(Base::Jedec)
+                .with_min_unit(min)
+                .with_max_unit(max);
+            fmt(formatted)
+        };
+        assert_eq!(bounded(512, Unit::KB, Unit::GB), "0.50 KB");
+        assert_eq!(bounded(1000, Unit::KB, Unit::GB), "0.98 KB");
+        assert_eq!(bounded(3 << 20, Unit::KB, Unit::KB), "3072 KB");
+        assert_eq!(bounded(2 << 40, Unit::KB, Unit::GB), "2048 GB");
+    }
+
+    #[test]
+    fn si() {
+        let si = |bytes: i64, base: Base| {
+            fmt(Size::from_bytes(bytes)
+                .format()
+                .with_base(base)
+                .with_style(Style::Si))
+        };
+        assert_eq!(si(1, Base::Base10), "1 B");
+        assert_eq!(si(512, Base::Base10), "512 B");
+        assert_eq!(si(1_020, Base::Base10), "1.02 kB");
+        assert_eq!(si(1_500_000, Base::Base10), "1.50 MB");
+        assert_eq!(si(1536, Base::Base2), "1.50 KiB");
+        assert_eq!(si(3 << 30, Base::Base2), "3.00 GiB");
+        // JEDEC labels aren't SI symbols, so the IEC symbols of the measured units are used.
+        assert_eq!(si(1536, Base::Jedec), "1.50 KiB");
+        assert_eq!(si(3 << 30, Base::Jedec), "3.00 GiB");
+        assert_eq!(si(512, Base::Jedec), "512 B");
+
+        let size = Size::from_bytes(1_500);
+        let formatted = size
+            .format()
+            .with_base(Base::Base10)
+            .with_style(Style::FullLowercase);
+        assert_eq!(fmt(formatted), "1.50 kilobytes");
+    }
+
+    #[test]
+    fn auto_base() {
+        let auto = |bytes: i64| {
+            let size = Size::from_bytes(bytes);
+            let formatted = size
+                .format()
+                .with_base(Base::Auto)
+                .with_precision(Precision::Significant(3))
+                .with_trailing_zeros(false);
+            fmt(formatted)
+        };
+        assert_eq!(auto(0), "0 bytes");
+        assert_eq!(auto(4096), "4 KiB");
+        assert_eq!(auto(8 << 30), "8 GiB");
+        assert_eq!(auto(4_000_000_000_000), "4 TB");
+        assert_eq!(auto(1_024_000), "1.02 MB");
+        assert_eq!(auto(-3_145_728), "-3 MiB");
+        assert_eq!(auto(1_500), "1.5 KB");
+        // Multiples of a power of 1000 are written in base-10 even if also a multiple of 1024, at
+        // the cost of exactness: 1000 MiB is rounded to "1.05 GB".
+        assert_eq!(auto(1_048_576_000), "1.05 GB");
+        let size = Size::from_mib(1000);
+        assert_eq!(fmt(size.format().with_base(Base::Auto)), "1.05 GB");
+        assert_eq!(fmt(size.format().with_base(Base::Base2)), "1000 MiB");
+
+        let compact = |bytes: i64| {
+            fmt(Size::from_bytes(bytes)
+                .format()
+                .with_base(Base::Auto)
+                .with_style(Style::Compact))
+        };
+        assert_eq!(compact(4096), "4.0K");
+        assert_eq!(compact(4000), "4.0k");
+    }
+}
@@ -1,13 +1,17 @@
+use core::str::FromStr;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::str::FromStr;
 
+#[cfg(test)]
 use crate::consts::*;
-use crate::Size;
+use crate::unit::trim_plural;
+use crate::{Base, Size, Unit};
 
-/// Represents an error parsing a `Size` from a string representation.
+/// Represents an error parsing a `Size` (or a [`Unit`](crate::Unit)) from a string representation.
 #[derive(Debug, PartialEq, Clone, Eq)]
 pub struct ParseSizeError;
 
+#[cfg(feature = "std")]
 impl Error for ParseSizeError {}
 impl core::fmt::Display for ParseSizeError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -29,6 +33,16 @@ impl Size {
     /// * 1234 bytes/kilobytes/terabytes/etc
     /// * 12.34 Kibibytes/MegaBytes/etc
     ///
+    /// The scalar is parsed exactly, as a fixed-point decimal (optionally with an exponent, e.g.
+    /// `1.5e3 KB`), and any fractional bytes are truncated. No floating-point math is involved, so
+    /// parsing is also available in `no_std` mode.
+    ///
+    /// As the scalar is no longer parsed as an `f64`, the non-finite values accepted by earlier
+    /// versions ("inf", "infinity", and "NaN", which were parsed as [`Size::MAX`] and a size of
+    /// zero bytes, respectively) are now rejected with a [`ParseSizeError`]. The same goes for
+    /// values too large for even a 128-bit number of bytes (e.g. "1e400"), while any smaller
+    /// out-of-range value (e.g. "9 EiB") still saturates at [`Size::MIN`] or [`Size::MAX`].
+    ///
     /// # Example
     ///
     /// ```rust
@@ -41,10 +55,32 @@ impl Size {
     pub fn from_str(s: &str) -> Result<Size, crate::ParseSizeError> {
         FromStr::from_str(s)
     }
+
+    /// Parse a string representation of size to a `Size` value, interpreting the unit names per
+    /// the provided [`Base`].
+    ///
+    /// With [`Base::Jedec`], the names of base-10 units (e.g. "KB" or "megabytes") refer to the
+    /// base-2 units of the same order (1024 bytes and 1024 KiB, respectively), matching the sizes
+    /// written by a [`SizeFormatter`](crate::SizeFormatter) configured with `Base::Jedec`. The
+    /// names of base-2 units are unambiguous and are parsed the same way in every base, so all
+    /// other bases behave exactly like [`Size::from_str()`].
+    ///
+    /// ```
+    /// use size::{Base, Size};
+    ///
+    /// assert_eq!(Size::from_str_with_base("1.5 KB", Base::Jedec), Ok(Size::from_bytes(1536)));
+    /// assert_eq!(Size::from_str_with_base("1.5 KiB", Base::Jedec), Ok(Size::from_bytes(1536)));
+    /// assert_eq!(Size::from_str_with_base("1.5 KB", Base::Base10), Ok(Size::from_bytes(1500)));
+    /// ```
+    pub fn from_str_with_base(s: &str, base: Base) -> Result<Size, crate::ParseSizeError> {
+        let bytes = parse_bytes_with(s.as_bytes(), matches!(base, Base::Jedec))?;
+        Ok(Size::from_const(crate::saturate(bytes)))
+    }
 }
 
 /// This test just ensures everything is wired up correctly between the member function
 /// `[Size::from_str()]` and the `FromStr` trait impl.
+#[cfg(feature = "float")]
 #[test]
 fn from_str() {
     let input = "12.34 kIloByte";
@@ -59,45 +95,320 @@ fn parse() {
     assert_eq!(size, Ok(Size::from_bytes(12 * KB + 340)));
 }
 
+#[test]
+fn parse_jedec() {
+    let jedec = |s: &str| Size::from_str_with_base(s, Base::Jedec);
+    assert_eq!(jedec("2 MB"), Ok(Size::from_mib(2)));
+    assert_eq!(jedec("2.5 gigabytes"), Ok(Size::from_mib(2560)));
+    assert_eq!(jedec("7 GiB"), Ok(Size::from_gib(7)));
+    assert_eq!(jedec("-1kb"), Ok(Size::from_bytes(-1024)));
+    assert_eq!(jedec("512"), Ok(Size::from_bytes(512)));
+    assert_eq!(jedec("9 EB"), Ok(Size::MAX));
+    assert_eq!(jedec("1 XB"), Err(ParseSizeError));
+
+    let base2 = Size::from_str_with_base("2 MB", Base::Base2);
+    assert_eq!(base2, Ok(Size::from_bytes(2 * MB)));
+}
+
 impl FromStr for Size {
     type Err = ParseSizeError;
 
+    /// Parses a textual size with exact fixed-point math (see [`Size::from_str()`]), saturating at
+    /// [`Size::MIN`] or [`Size::MAX`] if the result is out of range.
     fn from_str(s: &str) -> Result<Size, Self::Err> {
-        let s = s.trim();
+        let bytes = parse_bytes(s.as_bytes())?;
+        Ok(Size::from_const(crate::saturate(bytes)))
+    }
+}
 
-        // Try to split before the first unit char in the input. This supports the (unadvertised)
-        // ability to parse scientific notation w/o spaces between scalar and unit.
-        let (num_str, unit) = match s.rfind(|c: char| !c.is_ascii_alphabetic()).map(|i| i + 1) {
-            None => (s, ""), // just a number, no unit
-            Some(idx) => s.split_at(idx),
-        };
+/// Parses a textual size (in the same format as `impl FromStr for Size`) into an exact number of
+/// bytes, without using any floating-point math. This is a `const fn` so that it may be used to
+/// validate size literals at compile time (see the [`size!`](crate::size) macro).
+///
+/// The scalar is parsed as a fixed-point decimal: the significant digits are accumulated in a `u128`
+/// (which can hold up to 38 decimal digits, any further fractional digits being discarded) and
+/// multiplied by the unit before the decimal exponent is applied, truncating any fractional bytes
+/// toward zero. The result is returned as an `i128` so callers can apply their own range checks.
+pub(crate) const fn parse_bytes(s: &[u8]) -> Result<i128, ParseSizeError> {
+    parse_bytes_with(s, false)
+}
+
+/// The implementation of [`parse_bytes()`], optionally interpreting the names of base-10 units as
+/// their [`Base::Jedec`] (base-2) counterparts.
+const fn parse_bytes_with(s: &[u8], jedec: bool) -> Result<i128, ParseSizeError> {
+    // Trim leading and trailing whitespace
+    let mut start = 0;
+    let mut end = s.len();
+    while start < end && s[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && s[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+
+    // Split before the trailing run of alphabetic characters (the unit). This supports the
+    // (unadvertised) ability to parse scientific notation w/o spaces between scalar and unit.
+    let mut split = end;
+    while split > start && s[split - 1].is_ascii_alphabetic() {
+        split -= 1;
+    }
+    let (head, _) = s.split_at(end);
+    let (head, unit) = head.split_at(split);
+    let unit = if trim_plural(unit).is_empty() {
+        Unit::Byte
+    } else {
+        match Unit::from_name(unit) {
+            Some(unit) if jedec && matches!(unit.base(), Some(Base::Base10)) => unit.counterpart(),
+            Some(unit) => unit,
+            None => return Err(ParseSizeError),
+        }
+    };
+
+    // Trim whitespace between the scalar and the unit
+    let mut num_end = split;
+    while num_end > start && head[num_end - 1].is_ascii_whitespace() {
+        num_end -= 1;
+    }
+    let (number, _) = head.split_at(num_end);
+    let (_, number) = number.split_at(start);
+
+    let mut i = 0;
+    let negative = !number.is_empty() && number[0] == b'-';
+    if !number.is_empty() && (number[0] == b'-' || number[0] == b'+') {
+        i += 1;
+    }
+
+    // Accumulate the significant digits of the integral and fractional parts in `mantissa`, so that
+    // the scalar is equal to `mantissa * 10^exponent`.
+    let mut mantissa: u128 = 0;
+    let mut exponent: i64 = 0;
+    let mut digits = 0;
+    let mut fraction = false;
+    while i < number.len() {
+        let c = number[i];
+        if c == b'.' && !fraction {
+            fraction = true;
+        } else if c.is_ascii_digit() {
+            digits += 1;
+            match mantissa.checked_mul(10) {
+                Some(m) if m <= u128::MAX - 9 => {
+                    mantissa = m + (c - b'0') as u128;
+                    if fraction {
+                        exponent -= 1;
+                    }
+                }
+                // Too many significant digits: integral digits still scale the result, but
+                // fractional digits can safely be discarded.
+                _ if !fraction => exponent += 1,
+                _ => {}
+            }
+        } else {
+            break;
+        }
+        i += 1;
+    }
+    if digits == 0 {
+        return Err(ParseSizeError);
+    }
+
+    // Optional exponent, as supported by `f64::from_str()`
+    if i < number.len() && (number[i] == b'e' || number[i] == b'E') {
+        i += 1;
+        let exp_negative = i < number.len() && number[i] == b'-';
+        if i < number.len() && (number[i] == b'-' || number[i] == b'+') {
+            i += 1;
+        }
+        let mut exp: i64 = 0;
+        let mut exp_digits = 0;
+        while i < number.len() && number[i].is_ascii_digit() {
+            // Any exponent this large over- or underflows regardless of the mantissa
+            if exp < 1_000_000 {
+                exp = exp * 10 + (number[i] - b'0') as i64;
+            }
+            exp_digits += 1;
+            i += 1;
+        }
+        if exp_digits == 0 {
+            return Err(ParseSizeError);
+        }
+        exponent += if exp_negative { -exp } else { exp };
+    }
+    if i != number.len() {
+        return Err(ParseSizeError);
+    }
 
-        let number: f64 = num_str.trim_end().parse().map_err(|_| ParseSizeError)?;
-        let unit = unit.to_lowercase();
-
-        let multiplier = match unit.as_str().trim_end_matches('s') {
-            "" | "b" | "byte" => B,
-            "kb" | "kilobyte" => KB,
-            "mb" | "megabyte" => MB,
-            "gb" | "gigabyte" => GB,
-            "tb" | "terabyte" => TB,
-            "pb" | "petabyte" => PB,
-            "eb" | "exabyte" => EB,
-
-            "kib" | "kibibyte" => KiB,
-            "mib" | "mebibyte" => MiB,
-            "gib" | "gibibyte" => GiB,
-            "tib" | "tebibyte" => TiB,
-            "pib" | "pebibyte" => PiB,
-            "eib" | "exbibyte" => EiB,
-
-            _ => return Err(ParseSizeError),
+    if mantissa == 0 {
+        return Ok(0);
+    }
+
+    let (mut hi, mut lo) = wide_mul(mantissa, unit.bytes_wide() as u128);
+    if exponent >= 0 {
+        while exponent > 0 {
+            if hi != 0 {
+                return Err(ParseSizeError);
+            }
+            (hi, lo) = wide_mul(lo, 10);
+            exponent -= 1;
+        }
+    } else {
+        while exponent < 0 && (hi != 0 || lo != 0) {
+            let step = if exponent < -38 { 38 } else { -exponent };
+            (hi, lo) = wide_div(hi, lo, 10_u128.pow(step as u32));
+            exponent += step;
+        }
+    }
+
+    match (hi, negative) {
+        (0, false) if lo <= i128::MAX as u128 => Ok(lo as i128),
+        (0, true) if lo <= i128::MAX as u128 => Ok(-(lo as i128)),
+        (0, true) if lo == i128::MIN.unsigned_abs() => Ok(i128::MIN),
+        _ => Err(ParseSizeError),
+    }
+}
+
+/// Multiplies two `u128` values, returning the full 256-bit product as `(high, low)` halves.
+const fn wide_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (a1, a0) = (a >> 64, a & MASK);
+    let (b1, b0) = (b >> 64, b & MASK);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Divides the 256-bit value `(high, low)` by `divisor` (which must be less than 2^127), returning
+/// the truncated 256-bit quotient as `(high, low)` halves.
+const fn wide_div(hi: u128, lo: u128, divisor: u128) -> (u128, u128) {
+    let (mut q_hi, mut q_lo, mut rem) = (0_u128, 0_u128, 0_u128);
+
+    // Simple binary long division, one bit at a time
+    let mut bit = 256;
+    while bit > 0 {
+        bit -= 1;
+        let next = if bit >= 128 {
+            (hi >> (bit - 128)) & 1
+        } else {
+            (lo >> bit) & 1
         };
+        rem = (rem << 1) | next;
+        if rem >= divisor {
+            rem -= divisor;
+            if bit >= 128 {
+                q_hi |= 1 << (bit - 128);
+            } else {
+                q_lo |= 1 << bit;
+            }
+        }
+    }
+    (q_hi, q_lo)
+}
 
-        Ok(Size::from_bytes(number * multiplier as f64))
+impl Size {
+    /// Parse a string representation of a size to a `Size` value in a `const` context.
+    ///
+    /// This accepts the same formats as [`Size::from_str()`], but is available in `no_std` mode
+    /// and never uses floating-point math: the scalar is parsed as an exact fixed-point decimal and
+    /// any fractional bytes are truncated. Unlike `Size::from_str()`, sizes that cannot be
+    /// represented by a `Size` are rejected rather than clamped.
+    ///
+    /// The [`size!`](crate::size) macro wraps this function to turn size literals into `const`
+    /// values, turning invalid literals into compile-time errors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use size::Size;
+    ///
+    /// const LIMIT: Size = match Size::from_str_const("1.5 GiB") {
+    ///     Ok(size) => size,
+    ///     Err(_) => panic!("invalid size"),
+    /// };
+    /// assert_eq!(LIMIT, Size::from_mib(1536));
+    /// assert!(Size::from_str_const("9 EiB").is_err());
+    /// ```
+    pub const fn from_str_const(s: &str) -> Result<Size, ParseSizeError> {
+        match parse_bytes(s.as_bytes()) {
+            Ok(bytes) if bytes >= i64::MIN as i128 && bytes <= i64::MAX as i128 => {
+                Ok(Size::from_const(bytes as i64))
+            }
+            _ => Err(ParseSizeError),
+        }
     }
 }
 
+/// Creates a `const` [`Size`] from a size literal, validated at compile time.
+///
+/// The literal may be passed either as a string in any of the formats accepted by
+/// [`Size::from_str()`], or as a numeric literal followed by a unit. The size is parsed exactly by
+/// [`Size::from_str_const()`] and evaluated as a constant, so an unknown unit, a malformed number,
+/// or a size that overflows a `Size` results in a compilation error rather than a runtime panic.
+///
+/// # Example
+///
+/// ```rust
+/// use size::{size, Size};
+///
+/// const MAX_UPLOAD: Size = size!("4 GiB");
+/// const CHUNK: Size = size!(1.5 MiB);
+///
+/// assert_eq!(MAX_UPLOAD, Size::from_gib(4));
+/// assert_eq!(CHUNK, Size::from_kib(1536));
+/// assert_eq!(size!(512 bytes), Size::from_bytes(512));
+/// assert_eq!(size!(-2 KB), Size::from_bytes(-2000));
+/// ```
+///
+/// An invalid unit does not compile:
+///
+/// ```compile_fail
+/// const SIZE: size::Size = size::size!(4 XiB);
+/// ```
+///
+/// and neither does a size that does not fit in a `Size`:
+///
+/// ```compile_fail
+/// const SIZE: size::Size = size::size!("8 EiB");
+/// ```
+///
+/// A literal on its own must be a string literal; other literals (e.g. `size!(4)` or
+/// `size!(b"4 GiB")`) are rejected with an error naming the offending literal:
+///
+/// ```compile_fail
+/// const SIZE: size::Size = size::size!(4);
+/// ```
+#[macro_export]
+macro_rules! size {
+    ($value:literal $unit:ident) => {
+        $crate::size!(@parse concat!(stringify!($value), " ", stringify!($unit)))
+    };
+    ($text:literal) => {{
+        const _: () = {
+            let text = stringify!($text).as_bytes();
+            assert!(
+                !text.is_empty() && (text[0] == b'"' || text[0] == b'r'),
+                concat!(
+                    "size! expects a string literal or a number followed by a unit, found `",
+                    stringify!($text),
+                    "`"
+                )
+            );
+        };
+        // `concat!()` turns a rejected non-string literal into a string so that only the error
+        // above is reported.
+        $crate::size!(@parse concat!($text))
+    }};
+    (@parse $text:expr) => {{
+        const SIZE: $crate::Size = match $crate::Size::from_str_const($text) {
+            Ok(size) => size,
+            Err(_) => panic!(concat!("invalid or out-of-range size literal: ", $text)),
+        };
+        SIZE
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,7 +421,7 @@ mod tests {
 
     #[test]
     fn parse_abbr_unit() {
-        let tests = vec![
+        let tests = [
             ("1234B", 1234),
             ("1234 KB", 1234 * KB),
             ("1234KiB", 1234 * KiB),
@@ -126,7 +437,7 @@ mod tests {
 
     #[test]
     fn parse_full_unit() {
-        let tests = vec![
+        let tests = [
             ("1234 bytes", 1234),
             ("1234 kilobytes", 1234 * KB),
             ("1234 kibibytes", 1234 * KiB),
@@ -141,15 +452,27 @@ mod tests {
 
     #[test]
     fn parse_invalid_inputs() {
-        let tests = vec![
+        let tests = [
             "Not a number",
             "1234 XB",   // Unknown suffix
             "12..34 MB", // Invalid number format
         ];
 
         for input in tests {
-            assert_eq!(dbg!(Size::from_str(input)), Err(ParseSizeError));
+            assert_eq!(Size::from_str(input), Err(ParseSizeError), "parsing {:?}", input);
+        }
+    }
+
+    #[test]
+    fn parse_non_finite() {
+        // These were parsed by `f64::from_str()` (and saturated) before parsing became exact.
+        for input in ["inf", "Infinity", "inf KB", "NaN", "nan MiB", "-inf"] {
+            assert_eq!(Size::from_str(input), Err(ParseSizeError), "parsing {:?}", input);
         }
+        assert_eq!(Size::from_str("1e400"), Err(ParseSizeError));
+        assert_eq!(Size::from_str("-1e40 KB"), Err(ParseSizeError));
+        assert_eq!(Size::from_str("1e30"), Ok(Size::MAX));
+        assert_eq!(Size::from_str("-9 EiB"), Ok(Size::MIN));
     }
 
     #[test]
@@ -165,3 +488,78 @@ mod tests {
         assert_eq!(Size::from_str("0.423e3kb"), Ok(Size::from_bytes(423_000)));
     }
 }
+
+#[cfg(test)]
+mod const_tests {
+    use crate::consts::*;
+    use crate::Size;
+
+    #[test]
+    fn parse_const() {
+        let tests = [
+            ("1234", 1234),
+            (" 1234 ", 1234),
+            ("1234B", 1234),
+            ("1234 KB", 1234 * KB),
+            ("1234KiB", 1234 * KiB),
+            ("12.34 MB", 12_340_000),
+            ("12.34MiB", 12_939_427),
+            ("1234 kibibytes", 1234 * KiB),
+            ("12.34   gibibytes", 13_249_974_108),
+            ("42.0kib ", 42 * KiB),
+            ("0.423E3", 423),
+            ("423E-3 mb", 423_000),
+            ("0.423e3kb", 423_000),
+            ("+1.5 KiB", 1536),
+            ("-1.5 KiB", -1536),
+            (".5 KB", 500),
+            ("5. KB", 5000),
+            ("1.9999999999 B", 1),
+            ("-1.9999999999 B", -1),
+            ("1e-400 EB", 0),
+            ("0e400 EB", 0),
+            ("0.000000000000000000000000000000000000000001e60 B", 1_000_000_000_000_000_000),
+            ("12 s", 12),
+            ("9223372036854775807", i64::MAX),
+            ("-9223372036854775808 B", i64::MIN),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(Size::from_str_const(input), Ok(Size::from_bytes(expected)), "{}", input);
+        }
+    }
+
+    #[test]
+    fn parse_const_invalid() {
+        let tests = [
+            "",
+            "Not a number",
+            "1234 XB",
+            "12..34 MB",
+            "1.2.3",
+            ". KB",
+            "- KB",
+            "1e KB",
+            "1e+ KB",
+            "1 2 KB",
+            "8 EiB",
+            "9223372036854775808",
+            "-9223372036854775809",
+            "1e400 B",
+        ];
+
+        for input in tests {
+            assert!(Size::from_str_const(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn size_macro() {
+        const SIZE: Size = size!("4 GiB");
+        assert_eq!(SIZE, Size::from_gib(4));
+        assert_eq!(size!(4 GiB), Size::from_gib(4));
+        assert_eq!(size!(4.5 gigabytes), Size::from_mb(4500));
+        assert_eq!(size!(1e3 B), Size::from_kb(1));
+        assert_eq!(size!(-12 KiB), Size::from_kib(-12));
+    }
+}
@@ -1,5 +1,4 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(not(feature = "std"), allow(clippy::unnecessary_cast))]
 #![warn(missing_docs)]
 
 //! This crate provides an ergonomic, type-safe, and aesthetically-pleasing [`Size`] type that can
@@ -26,8 +25,8 @@
 //! base-2 (KiB, MiB, etc) and base-10 (KB, MB, etc) units are supported and are exposed via the
 //! same API. You can either use the abbreviated form of the unit to instantiate your type, or use
 //! the full unit name to be more expressive. Here's an example:
-#![cfg_attr(not(feature = "std"), doc = "```ignore")]
-#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(all(feature = "std", feature = "float")), doc = "```ignore")]
+#![cfg_attr(all(feature = "std", feature = "float"), doc = "```")]
 //! use size::Size;
 //!
 //! // Create a strongly-typed size object. We don't even need to specify a numeric type!
@@ -46,6 +45,15 @@
 //! assert_eq!(file_size.bytes(), 4_294_967_296);
 //! ```
 //!
+//! Alternatively, the [`SizeExt`] trait can be brought into scope to create sizes directly from
+//! numeric values:
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+#![cfg_attr(feature = "std", doc = "```")]
+//! use size::{Size, SizeExt};
+//!
+//! assert_eq!(64.mib() + 512.kib(), Size::from_kib(66_048));
+//! ```
+//!
 //! All `Size` types can be directly compared (both for order and equality) to one another (or to
 //! references of one another), regardless of their original type:
 //! ```
@@ -64,7 +72,7 @@
 //!
 //! The majority of users will be interested in this crate for its ability to "pretty print" sizes
 //! with little ceremony and great results. All `Size` instances implement both
-//! [`std::fmt::Display`] and [`std::fmt::Debug`], so you can just directly `format!(...)` or
+//! [`core::fmt::Display`] and [`core::fmt::Debug`], so you can just directly `format!(...)` or
 //! `println!(...)` with whatever `Size` you have on hand:
 #![cfg_attr(not(feature = "std"), doc = "```ignore")]
 #![cfg_attr(feature = "std", doc = "```")]
@@ -85,6 +93,25 @@
 //! assert_eq!(file_size.to_string(), "1.28 MiB".to_string());
 //! ```
 //!
+//! The standard width, fill, alignment, sign, and precision flags are honored, with the precision
+//! setting the number of digits after the decimal point:
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+#![cfg_attr(feature = "std", doc = "```")]
+//! use size::Size;
+//!
+//! let file_size = Size::from_bytes(1_340_249);
+//! assert_eq!(format!("[{:>10.1}]", file_size), "[   1.3 MiB]");
+//! assert_eq!(format!("[{:+<10}]", file_size), "[1.28 MiB++]");
+//! ```
+//!
+//! The number is always derived from the exact number of bytes with integer math (in `no_std`
+//! builds, too) and rounded half-to-even unless configured otherwise (see
+//! [`SizeFormatter::with_rounding()`]). Earlier versions divided through an `f64` instead, which
+//! can't represent most decimal fractions exactly and so rounded ties up or down seemingly at
+//! random. As a result, some sizes are now written differently, e.g. 1015 bytes are "1.02 KB" in
+//! base-10 (previously "1.01 KB", since 1.015 is stored as 1.01499...) and 1145 bytes are "1.14 KB"
+//! (previously "1.15 KB").
+//!
 //! For fine-grained control over how a size is formatted and displayed, you can manually use the
 //! [`Size::format()`] function, which returns a [`FormattableSize`](crate::fmt::FormattableSize)
 //! implementing the builder model to allow you to change one or more properties of how a `Size`
@@ -106,12 +133,18 @@
 //! wrapping file sizes in strongly-typed `Size` structs, which should always be the initial
 //! instinct.
 //!
+//! Sizes that can never be negative but may exceed the 8 EiB range of a `Size` (e.g. running byte
+//! counters) can instead be held in a [`ByteCount`], an unsigned companion type backed by a `u64`
+//! that shares the same constructors, formatting, and parsing. Sizes beyond that (e.g. aggregate
+//! storage across a fleet) can be held in a 128-bit [`WideSize`], which also supports the units
+//! from zettabytes/zebibytes up to quettabytes/quebibytes.
+//!
 //! ## Mathematical operations
 //!
 //! You can perform mathematical operations on `Size` types and the type safety makes sure that
 //! what you're doing makes sense:
-#![cfg_attr(not(feature = "std"), doc = "```ignore")]
-#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(all(feature = "std", feature = "float")), doc = "```ignore")]
+#![cfg_attr(all(feature = "std", feature = "float"), doc = "```")]
 //! use size::Size;
 //!
 //! let sum = Size::from_mib(2) + Size::from_kib(200);
@@ -123,6 +156,12 @@
 //!
 //! See the documentation of the [`ops`] module for more on this topic.
 //!
+//! ## Rounding fractional sizes
+//!
+//! Sizes created from floating-point values via the generic constructors discard any fractional
+//! bytes. [`Size::from_f64_with()`] accepts a [`RoundingMode`] to round up, down, or to the nearest
+//! byte instead, or to reject fractional bytes altogether. See the [`rounding`] module for more.
+//!
 //! ## Parsing sizes from text
 //!
 //! The [`Size::from_str()`] function can be used to convert the most commonly encountered textual
@@ -130,24 +169,42 @@
 //! various input whitespace formatting, abbreviated/full unit names, mixed upper/lower-case
 //! representation, etc.
 //!
+//! Size literals embedded in code can instead be parsed at compile time with the [`size!`] macro,
+//! which produces a `const` `Size` and turns an invalid literal into a compilation error:
+//! ```
+//! use size::{size, Size};
+//!
+//! const MAX_UPLOAD: Size = size!(4 GiB);
+//! assert_eq!(MAX_UPLOAD, Size::from_gib(4));
+//! ```
+//!
 //! ## Crate features
 //!
 //! The following crate features may be chosen:
-//! * `std` (enabled by default)
+//! * `std` (enabled by default, implies `alloc`)
+//! * `alloc`
+//! * `float` (enabled by default)
+//! * `libm` (implies `float`)
 //! * `serde`
 //!
+//! Floating-point support is controlled by the `float` feature alone, independent of `std`. It is
+//! enabled by default so that existing code passing floating-point values keeps compiling. Without
+//! it, no floating-point math is performed: to prevent inadvertent loss of precision, it is
+//! forbidden to pass in floating point values to the `Size` API (e.g. `Size::from_mib(1.5)`), and
+//! [`Size::from_f64_with()`], [`Size::as_unit()`], and [`FromFloatError`] are removed. With `std`,
+//! the standard library's floating-point functions are used; `no_std` builds must enable the
+//! `libm` feature instead of `float`, which pulls in the `libm` crate to provide them.
+//!
 //! If compiled without the `std` feature (i.e. with `--no-default-features` or used as a dependency
 //! with default features disabled), the crate becomes `no_std` compatible. When used in `no_std`
 //! mode, the following restrictions and limitations are observed:
 //!
-//! * All formatting/stringification of `Size` types is disabled.
-//! * `Size` no longer implements [`std::fmt::Display`] (`core::fmt::Debug` is still implemented).
-//! * The intermediate type used for mathematical operations on `Size` types is changed from `f64`
-//! to `i64` so that no implicit floating-point math is performed. To prevent inadvertent loss of
-//! precision, it is forbidden to pass in floating point values to the `Size` API under `no_std`
-//! mode.
-//! * The ability to parse strings into `Size` objects (`Size::from_str()` and the `FromStr` impl)
-//! are removed.
+//! * Sizes can still be formatted via [`core::fmt::Display`] and [`Size::format()`] (which never
+//! allocate and use only integer math), but [`SizeFormatter::format()`], which returns a `String`,
+//! is only available if the `alloc` feature is enabled.
+//! * The error types do not implement `std::error::Error`.
+//!
+//! Parsing (via [`core::str::FromStr`]) and `serde` support are available in every configuration.
 //!
 //! ## Base-2 and Base-10 constants
 //!
@@ -155,6 +212,10 @@
 //! constants into scope with `use size::consts::*` (or just `use size::*`, but that also imports
 //! the types and traits defined by this crate, too).
 //!
+//! The same units are also available as a strongly-typed [`Unit`] enum, which additionally exposes
+//! each unit's [`Base`] and names and can be iterated over or parsed from text. See the [`unit`](mod@unit)
+//! module for more information.
+//!
 //! ## Serialization support
 //!
 //! If the crate is compiled with the optional (default: disabled) `serde` feature, the `Size` type
@@ -167,83 +228,60 @@
 //! As an example, `struct File { name: String, size: Size } ` will serialize to `{ name: "name",
 //! size: 1234 }` instead of `{ name: "name", size: { bytes: 1234 }`.
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(feature = "float", not(feature = "std"), not(feature = "libm")))]
+compile_error!("the `float` feature requires either the `std` or the `libm` feature");
+
+mod byte_count;
+pub mod convert;
+pub mod ext;
 pub mod fmt;
-#[cfg(feature = "std")]
 mod from_str;
 pub mod ops;
+pub mod rounding;
 #[cfg(feature = "serde")]
 mod serde;
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
 mod tests_nostd;
+pub mod unit;
+mod wide;
 
+pub use crate::byte_count::ByteCount;
 pub use crate::consts::*;
-#[cfg(feature = "std")]
-pub use crate::fmt::{Base, SizeFormatter, Style};
-#[cfg(feature = "std")]
+pub use crate::convert::{IntoSize, TryFromSizeError, TryIntoSize};
+pub use crate::ext::SizeExt;
+pub use crate::fmt::{Precision, Separator, SizeFormatter, Style};
 pub use crate::from_str::ParseSizeError;
-use crate::sealed::AsIntermediate;
-
-#[cfg(feature = "std")]
-type Intermediate = f64;
-#[cfg(not(feature = "std"))]
-type Intermediate = i64;
+#[cfg(feature = "float")]
+pub use crate::rounding::FromFloatError;
+pub use crate::rounding::RoundingMode;
+pub use crate::unit::{Base, Unit};
+pub use crate::wide::WideSize;
 
-#[cfg(feature = "std")]
 const DEFAULT_BASE: Base = Base::Base2;
-#[cfg(feature = "std")]
 const DEFAULT_STYLE: Style = Style::Default;
-#[cfg(feature = "std")]
-const DEFAULT_SCALE: Option<usize> = None;
-
-mod sealed {
-    use super::Intermediate;
-
-    pub trait AsIntermediate: Sized {
-        // This is the same name and signature as `AsPrimitive` trait from the `num_traits` crate
-        fn as_(self) -> Intermediate;
-    }
-
-    macro_rules! as_intermediate {
-        ($type:ty) => {
-            impl AsIntermediate for $type {
-                fn as_(self) -> Intermediate {
-                    use core::mem::size_of;
-                    const SIGNED_MAX: $type = Intermediate::MAX as $type;
-
-                    // A separate implementation is required for no_std's intermediate i64 to make
-                    // sure u64::MAX is clamped to i64::MAX rather than cast directly to -1. The
-                    // first three checks should be elided per impl via compile-time optimization.
-                    if cfg!(not(feature = "std")) // we are in no_std mode
-                        && <$type>::MIN == 0 as $type // it's an unsigned type
-                        && size_of::<Intermediate>() >= size_of::<$type>() // with a greater +range
-                        && self > SIGNED_MAX // and exceeds our max
-                    {
-                        Intermediate::MAX
-                    } else {
-                        self as Intermediate
-                    }
-                }
-            }
-        };
-    }
-
-    as_intermediate!(u8);
-    as_intermediate!(u16);
-    as_intermediate!(u32);
-    as_intermediate!(u64);
-    as_intermediate!(usize);
-    as_intermediate!(i8);
-    as_intermediate!(i16);
-    as_intermediate!(i32);
-    as_intermediate!(i64);
-    as_intermediate!(isize);
-    #[cfg(feature = "std")]
-    as_intermediate!(f32);
-    #[cfg(feature = "std")]
-    as_intermediate!(f64);
+const DEFAULT_SEPARATOR: Separator = Separator::Space;
+const DEFAULT_PRECISION: Option<Precision> = None;
+const DEFAULT_TRIM_ZEROS: bool = false;
+const DEFAULT_ROUNDING: Option<RoundingMode> = None;
+const DEFAULT_THRESHOLD: Option<u16> = None;
+const DEFAULT_UNIT: Option<Unit> = None;
+const DEFAULT_MIN_UNIT: Option<Unit> = None;
+const DEFAULT_MAX_UNIT: Option<Unit> = None;
+
+/// Clamps a (possibly out-of-range) number of bytes to the range of a `Size`.
+const fn saturate(bytes: i128) -> i64 {
+    if bytes > i64::MAX as i128 {
+        i64::MAX
+    } else if bytes < i64::MIN as i128 {
+        i64::MIN
+    } else {
+        bytes as i64
+    }
 }
 
 /// A collection of constants for base-2 and base-10 units.
@@ -258,25 +296,29 @@ mod sealed {
 /// pub const TOTAL_SIZE: Size = Size::from_const(3 * MiB);
 /// ```
 ///
+/// (Though the `const` per-unit constructors such as [`Size::from_mib_const()`] are more direct.)
+///
 /// You can use these directly from the root `size` namespace (e.g. `size::KiB`) or import all size
 /// constants with `use size::consts::*` to get access to them all in the scope.
 pub mod consts {
     #![allow(non_upper_case_globals)]
 
+    use crate::Unit;
+
     /// Basic "byte" constant, used across all bases.
-    pub const BYTE: i64 = 1;
+    pub const BYTE: i64 = Unit::Byte.bytes();
     /// Base-10 "kilobyte" constant, equal to 1000 bytes.
-    pub const KILOBYTE: i64 = 1000 * BYTE;
+    pub const KILOBYTE: i64 = Unit::Kilobyte.bytes();
     /// Base-10 "megabyte" constant, equal to 1000 kilobytes.
-    pub const MEGABYTE: i64 = 1000 * KILOBYTE;
+    pub const MEGABYTE: i64 = Unit::Megabyte.bytes();
     /// Base-10 "gigabyte" constant, equal to 1000 megabytes.
-    pub const GIGABYTE: i64 = 1000 * MEGABYTE;
+    pub const GIGABYTE: i64 = Unit::Gigabyte.bytes();
     /// Base-10 "terabyte" constant, equal to 1000 gigabytes.
-    pub const TERABYTE: i64 = 1000 * GIGABYTE;
+    pub const TERABYTE: i64 = Unit::Terabyte.bytes();
     /// Base-10 "petabyte" constant, equal to 1000 terabytes.
-    pub const PETABYTE: i64 = 1000 * TERABYTE;
+    pub const PETABYTE: i64 = Unit::Petabyte.bytes();
     /// Base-10 "exabyte" constant, equal to 1000 petabytes.
-    pub const EXABYTE: i64 = 1000 * PETABYTE;
+    pub const EXABYTE: i64 = Unit::Exabyte.bytes();
 
     /// Abbreviated "byte" constant. Identical to [`BYTE`].
     pub const B: i64 = BYTE;
@@ -294,17 +336,17 @@ pub mod consts {
     pub const EB: i64 = EXABYTE;
 
     /// Base-2 "kibibyte" constant, equal to 2^10 bytes.
-    pub const KIBIBYTE: i64 = 1 << 10;
+    pub const KIBIBYTE: i64 = Unit::Kibibyte.bytes();
     /// Base-2 "mebibyte" constant, equal to 2^20 bytes.
-    pub const MEBIBYTE: i64 = 1 << 20;
+    pub const MEBIBYTE: i64 = Unit::Mebibyte.bytes();
     /// Base-2 "gibibyte" constant, equal to 2^30 bytes.
-    pub const GIBIBYTE: i64 = 1 << 30;
+    pub const GIBIBYTE: i64 = Unit::Gibibyte.bytes();
     /// Base-2 "tebibyte" constant, equal to 2^40 bytes.
-    pub const TEBIBYTE: i64 = 1 << 40;
+    pub const TEBIBYTE: i64 = Unit::Tebibyte.bytes();
     /// Base-2 "pebibyte" constant, equal to 2^50 bytes.
-    pub const PEBIBYTE: i64 = 1 << 50;
+    pub const PEBIBYTE: i64 = Unit::Pebibyte.bytes();
     /// Base-2 "exbibyte" constant, equal to 2^60 bytes.
-    pub const EXBIBYTE: i64 = 1 << 60;
+    pub const EXBIBYTE: i64 = Unit::Exbibyte.bytes();
 
     /// Abbreviated base-2 "kibibyte" constant, equal to 1024 bytes. Identical to [`KIBIBYTE`].
     pub const KiB: i64 = KIBIBYTE;
@@ -318,6 +360,49 @@ pub mod consts {
     pub const PiB: i64 = PEBIBYTE;
     /// Abbreviated base-2 "exbibyte" constant, equal to 1024 pebibytes. Identical to [`EXBIBYTE`].
     pub const EiB: i64 = EXBIBYTE;
+
+    // Units from here on are too large for an `i64` (and a `Size`), so these constants are `i128`
+    // values for use with a `WideSize`.
+
+    /// Base-10 "zettabyte" constant, equal to 1000 exabytes.
+    pub const ZETTABYTE: i128 = Unit::Zettabyte.bytes_wide();
+    /// Base-10 "yottabyte" constant, equal to 1000 zettabytes.
+    pub const YOTTABYTE: i128 = Unit::Yottabyte.bytes_wide();
+    /// Base-10 "ronnabyte" constant, equal to 1000 yottabytes.
+    pub const RONNABYTE: i128 = Unit::Ronnabyte.bytes_wide();
+    /// Base-10 "quettabyte" constant, equal to 1000 ronnabytes.
+    pub const QUETTABYTE: i128 = Unit::Quettabyte.bytes_wide();
+
+    /// Abbreviated base-10 "zettabyte" constant, equal to 1000 exabytes. Identical to
+    /// [`ZETTABYTE`].
+    pub const ZB: i128 = ZETTABYTE;
+    /// Abbreviated base-10 "yottabyte" constant, equal to 1000 zettabytes. Identical to
+    /// [`YOTTABYTE`].
+    pub const YB: i128 = YOTTABYTE;
+    /// Abbreviated base-10 "ronnabyte" constant, equal to 1000 yottabytes. Identical to
+    /// [`RONNABYTE`].
+    pub const RB: i128 = RONNABYTE;
+    /// Abbreviated base-10 "quettabyte" constant, equal to 1000 ronnabytes. Identical to
+    /// [`QUETTABYTE`].
+    pub const QB: i128 = QUETTABYTE;
+
+    /// Base-2 "zebibyte" constant, equal to 2^70 bytes.
+    pub const ZEBIBYTE: i128 = Unit::Zebibyte.bytes_wide();
+    /// Base-2 "yobibyte" constant, equal to 2^80 bytes.
+    pub const YOBIBYTE: i128 = Unit::Yobibyte.bytes_wide();
+    /// Base-2 "robibyte" constant, equal to 2^90 bytes.
+    pub const ROBIBYTE: i128 = Unit::Robibyte.bytes_wide();
+    /// Base-2 "quebibyte" constant, equal to 2^100 bytes.
+    pub const QUEBIBYTE: i128 = Unit::Quebibyte.bytes_wide();
+
+    /// Abbreviated base-2 "zebibyte" constant, equal to 1024 exbibytes. Identical to [`ZEBIBYTE`].
+    pub const ZiB: i128 = ZEBIBYTE;
+    /// Abbreviated base-2 "yobibyte" constant, equal to 1024 zebibytes. Identical to [`YOBIBYTE`].
+    pub const YiB: i128 = YOBIBYTE;
+    /// Abbreviated base-2 "robibyte" constant, equal to 1024 yobibytes. Identical to [`ROBIBYTE`].
+    pub const RiB: i128 = ROBIBYTE;
+    /// Abbreviated base-2 "quebibyte" constant, equal to 1024 robibytes. Identical to [`QUEBIBYTE`].
+    pub const QiB: i128 = QUEBIBYTE;
 }
 
 /// `Size` is the core type exposed by this crate and allows the developer to express a file size
@@ -326,13 +411,21 @@ pub mod consts {
 ///
 /// A size can be created in terms of any supported unit and an associated numeric value of any
 /// type.
-#[cfg_attr(not(feature = "std"), doc = "```ignore")]
-#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(all(feature = "std", feature = "float")), doc = "```ignore")]
+#[cfg_attr(all(feature = "std", feature = "float"), doc = "```")]
 /// use size::Size;
 ///
 /// // Identical sizes expressed in different units with different primitive types:
 /// assert_eq!(Size::from_kibibytes(2_u8), Size::from_kilobytes(2.048_f64));
 /// ```
+///
+/// Sizes created from integral values are always calculated exactly (and are clamped to the range
+/// of a `Size` if they would otherwise overflow), regardless of the magnitude of the value. Only
+/// sizes created from floating-point values are calculated via floating-point math.
+///
+/// The generic constructors accept any type implementing [`IntoSize`], which includes
+/// `NonZero` integers and may be implemented for your own types. See the [`convert`] module for
+/// this and for the `From`/`TryFrom` conversions between `Size` and the primitive integer types.
 #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub struct Size {
     bytes: i64,
@@ -342,6 +435,12 @@ impl Size {
     /// Zero value (0 bytes)
     pub const ZERO: Self = Self { bytes: 0 };
 
+    /// The largest size that can be expressed by a `Size` (`i64::MAX` bytes, just under 8 EiB).
+    pub const MAX: Self = Self { bytes: i64::MAX };
+
+    /// The smallest (most negative) size that can be expressed by a `Size` (`i64::MIN` bytes).
+    pub const MIN: Self = Self { bytes: i64::MIN };
+
     /// Initialize a `Size` from the provided value, in bytes. This is a constant function and may
     /// be used in a `const` context.
     ///
@@ -370,159 +469,306 @@ impl Size {
     }
 
     /// Initialize a `Size` from the provided value, in bytes.
-    pub fn from_bytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_bytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: value.as_() as i64,
+            bytes: saturate(value.into_bytes(BYTE as i128)),
         }
     }
 
     /// Express a size in kilobytes. Actual size is 10^3 \* the value.
-    pub fn from_kilobytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_kilobytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * KILOBYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(KILOBYTE as i128)),
         }
     }
 
     /// Express a size in megabytes. Actual size is 10^6 \* the value.
-    pub fn from_megabytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_megabytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * MEGABYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(MEGABYTE as i128)),
         }
     }
 
     /// Express a size in gigabytes. Actual size is 10^9 \* the value.
-    pub fn from_gigabytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_gigabytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * GIGABYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(GIGABYTE as i128)),
         }
     }
 
     /// Express a size in terabytes. Actual size is 10^12 \* the value.
-    pub fn from_terabytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_terabytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * TERABYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(TERABYTE as i128)),
         }
     }
 
     /// Express a size in petabytes. Actual size is 10^15 \* the value.
-    pub fn from_petabytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_petabytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * PETABYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(PETABYTE as i128)),
         }
     }
 
     /// Express a size in exabytes. Actual size is 10^18 \* the value.
-    pub fn from_exabytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_exabytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * EXABYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(EXABYTE as i128)),
         }
     }
 
     #[inline]
     /// Express a size in kilobytes, as a shortcut for using [`Size::from_kilobytes()`].
-    pub fn from_kb<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_kb<T: IntoSize>(value: T) -> Self {
         Self::from_kilobytes(value)
     }
     #[inline]
     /// Express a size in megabytes, as a shortcut for using [`Size::from_megabytes()`].
-    pub fn from_mb<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_mb<T: IntoSize>(value: T) -> Self {
         Self::from_megabytes(value)
     }
     #[inline]
     /// Express a size in gigabytes, as a shortcut for using [`Size::from_gigabytes()`].
-    pub fn from_gb<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_gb<T: IntoSize>(value: T) -> Self {
         Self::from_gigabytes(value)
     }
     #[inline]
     /// Express a size in terabytes, as a shortcut for using [`Size::from_terabytes()`].
-    pub fn from_tb<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_tb<T: IntoSize>(value: T) -> Self {
         Self::from_terabytes(value)
     }
     #[inline]
     /// Express a size in petabytes, as a shortcut for using [`Size::from_petabytes()`].
-    pub fn from_pb<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_pb<T: IntoSize>(value: T) -> Self {
         Self::from_petabytes(value)
     }
     #[inline]
     /// Express a size in exabytes, as a shortcut for using [`Size::from_exabytes()`].
-    pub fn from_eb<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_eb<T: IntoSize>(value: T) -> Self {
         Self::from_exabytes(value)
     }
 
     /// Express a size in kibibytes. Actual size is 2^10 \* the value.
-    pub fn from_kibibytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_kibibytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * KIBIBYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(KIBIBYTE as i128)),
         }
     }
 
     /// Express a size in mebibytes. Actual size is 2^20 \* the value.
-    pub fn from_mebibytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_mebibytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * MEBIBYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(MEBIBYTE as i128)),
         }
     }
 
     /// Express a size in gibibytes. Actual size is 2^30 \* the value.
-    pub fn from_gibibytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_gibibytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * GIBIBYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(GIBIBYTE as i128)),
         }
     }
 
     /// Express a size in tebibytes. Actual size is 2^40 \* the value.
-    pub fn from_tebibytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_tebibytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * TEBIBYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(TEBIBYTE as i128)),
         }
     }
 
     /// Express a size in pebibytes. Actual size is 2^50 \* the value.
-    pub fn from_pebibytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_pebibytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * PEBIBYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(PEBIBYTE as i128)),
         }
     }
 
     /// Express a size in exbibytes. Actual size is 2^60 \* the value.
-    pub fn from_exbibytes<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_exbibytes<T: IntoSize>(value: T) -> Self {
         Self {
-            bytes: (value.as_() * EXBIBYTE as Intermediate) as i64,
+            bytes: saturate(value.into_bytes(EXBIBYTE as i128)),
         }
     }
 
     #[inline]
     /// Express a size in kibibytes, as a shortcut for using [`Size::from_kibibytes()`].
-    pub fn from_kib<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_kib<T: IntoSize>(value: T) -> Self {
         Self::from_kibibytes(value)
     }
     #[inline]
     /// Express a size in mebibytes, as a shortcut for using [`Size::from_mebibytes()`].
-    pub fn from_mib<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_mib<T: IntoSize>(value: T) -> Self {
         Self::from_mebibytes(value)
     }
     #[inline]
     /// Express a size in gibibytes, as a shortcut for using [`Size::from_gibibytes()`].
-    pub fn from_gib<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_gib<T: IntoSize>(value: T) -> Self {
         Self::from_gibibytes(value)
     }
     #[inline]
     /// Express a size in tebibytes, as a shortcut for using [`Size::from_tebibytes()`].
-    pub fn from_tib<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_tib<T: IntoSize>(value: T) -> Self {
         Self::from_tebibytes(value)
     }
     #[inline]
     /// Express a size in pebibytes, as a shortcut for using [`Size::from_pebibytes()`].
-    pub fn from_pib<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_pib<T: IntoSize>(value: T) -> Self {
         Self::from_pebibytes(value)
     }
     #[inline]
     /// Express a size in exbibytes, as a shortcut for using [`Size::from_exbibytes()`].
-    pub fn from_eib<T: AsIntermediate>(value: T) -> Self {
+    pub fn from_eib<T: IntoSize>(value: T) -> Self {
         Self::from_exbibytes(value)
     }
 }
 
+// `const` counterparts to the generic constructors and to the (non-const) trait-based comparisons,
+// for use in `const` and `static` items.
+impl Size {
+    /// Express a size as `value` of the provided [`Unit`] in a `const` context. The result saturates
+    /// at [`Size::MIN`] or [`Size::MAX`] like the generic constructors; use
+    /// [`Size::checked_mul()`] to detect overflow instead.
+    ///
+    /// The per-unit shortcuts (e.g. [`Size::from_mib_const()`]) may be more readable.
+    ///
+    /// ```
+    /// use size::{Size, Unit};
+    ///
+    /// const MAX_UPLOAD: Size = Size::from_mib_const(512);
+    /// const CHUNK: Size = Size::from_units_const(64, Unit::KiB);
+    /// const _: () = assert!(CHUNK.lt_const(&MAX_UPLOAD));
+    ///
+    /// assert_eq!(MAX_UPLOAD, Size::from_mib(512));
+    /// ```
+    pub const fn from_units_const(value: i64, unit: Unit) -> Self {
+        Self {
+            bytes: saturate((value as i128).saturating_mul(unit.bytes_wide())),
+        }
+    }
+
+    #[inline]
+    /// Express a size in kilobytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_kb_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Kilobyte)
+    }
+    #[inline]
+    /// Express a size in megabytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_mb_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Megabyte)
+    }
+    #[inline]
+    /// Express a size in gigabytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_gb_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Gigabyte)
+    }
+    #[inline]
+    /// Express a size in terabytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_tb_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Terabyte)
+    }
+    #[inline]
+    /// Express a size in petabytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_pb_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Petabyte)
+    }
+    #[inline]
+    /// Express a size in exabytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_eb_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Exabyte)
+    }
+    #[inline]
+    /// Express a size in kibibytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_kib_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Kibibyte)
+    }
+    #[inline]
+    /// Express a size in mebibytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_mib_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Mebibyte)
+    }
+    #[inline]
+    /// Express a size in gibibytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_gib_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Gibibyte)
+    }
+    #[inline]
+    /// Express a size in tebibytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_tib_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Tebibyte)
+    }
+    #[inline]
+    /// Express a size in pebibytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_pib_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Pebibyte)
+    }
+    #[inline]
+    /// Express a size in exbibytes in a `const` context. See [`Size::from_units_const()`].
+    pub const fn from_eib_const(value: i64) -> Self {
+        Self::from_units_const(value, Unit::Exbibyte)
+    }
+
+    /// Compares two sizes in a `const` context, equivalent to [`Ord::cmp()`].
+    pub const fn cmp_const(&self, other: &Size) -> core::cmp::Ordering {
+        use core::cmp::Ordering;
+
+        if self.bytes < other.bytes {
+            Ordering::Less
+        } else if self.bytes > other.bytes {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Returns `true` if both sizes are equal, equivalent to [`PartialEq::eq()`] in a `const`
+    /// context.
+    pub const fn eq_const(&self, other: &Size) -> bool {
+        self.bytes == other.bytes
+    }
+
+    /// Returns `true` if `self` is smaller than `other`, equivalent to [`PartialOrd::lt()`] in a
+    /// `const` context.
+    pub const fn lt_const(&self, other: &Size) -> bool {
+        self.bytes < other.bytes
+    }
+
+    /// Returns `true` if `self` is smaller than or equal to `other`, equivalent to
+    /// [`PartialOrd::le()`] in a `const` context.
+    pub const fn le_const(&self, other: &Size) -> bool {
+        self.bytes <= other.bytes
+    }
+
+    /// Returns `true` if `self` is larger than `other`, equivalent to [`PartialOrd::gt()`] in a
+    /// `const` context.
+    pub const fn gt_const(&self, other: &Size) -> bool {
+        self.bytes > other.bytes
+    }
+
+    /// Returns `true` if `self` is larger than or equal to `other`, equivalent to
+    /// [`PartialOrd::ge()`] in a `const` context.
+    pub const fn ge_const(&self, other: &Size) -> bool {
+        self.bytes >= other.bytes
+    }
+
+    /// Returns the smaller of two sizes, equivalent to [`Ord::min()`] in a `const` context.
+    pub const fn min_const(self, other: Size) -> Size {
+        if self.bytes <= other.bytes {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the larger of two sizes, equivalent to [`Ord::max()`] in a `const` context.
+    pub const fn max_const(self, other: Size) -> Size {
+        if self.bytes > other.bytes {
+            self
+        } else {
+            other
+        }
+    }
+}
+
 impl Size {
     #[inline]
     /// Returns the effective size in bytes of the type, useful for obtaining a plain/scalar
@@ -540,6 +786,122 @@ impl Size {
     }
 }
 
+impl Size {
+    /// Returns the size expressed as a (possibly fractional) number of the provided [`Unit`].
+    ///
+    /// This is only available with the `float` feature, as it uses floating-point math. See
+    /// [`Size::div_rem_unit()`] for an exact, integral alternative.
+    ///
+    /// Example:
+    /// ```
+    /// use size::{Size, Unit};
+    ///
+    /// let size = Size::from_kib(1536);
+    /// assert_eq!(size.as_unit(Unit::MiB), 1.5);
+    /// assert_eq!(size.as_mib(), 1.5);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn as_unit(&self, unit: Unit) -> f64 {
+        self.bytes as f64 / unit.bytes_wide() as f64
+    }
+
+    /// Returns the number of whole `unit`s in the size along with the remainder that does not
+    /// amount to a whole `unit`, without any loss of precision.
+    ///
+    /// As with integer division, the quotient is rounded toward zero and the remainder has the same
+    /// sign as the size itself.
+    ///
+    /// Example:
+    /// ```
+    /// use size::{Size, Unit};
+    ///
+    /// let size = Size::from_gib(3) + Size::from_mib(512);
+    /// assert_eq!(size.div_rem_unit(Unit::GiB), (3, Size::from_mib(512)));
+    ///
+    /// let size = Size::from_bytes(-1500);
+    /// assert_eq!(size.div_rem_unit(Unit::KB), (-1, Size::from_bytes(-500)));
+    /// ```
+    pub const fn div_rem_unit(&self, unit: Unit) -> (i64, Size) {
+        // Both results always fit in an `i64`, as `multiplier` is at least one.
+        let bytes = self.bytes as i128;
+        let multiplier = unit.bytes_wide();
+        ((bytes / multiplier) as i64, Size::from_const((bytes % multiplier) as i64))
+    }
+
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of kilobytes.
+    pub fn as_kb(&self) -> f64 {
+        self.as_unit(Unit::Kilobyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of megabytes.
+    pub fn as_mb(&self) -> f64 {
+        self.as_unit(Unit::Megabyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of gigabytes.
+    pub fn as_gb(&self) -> f64 {
+        self.as_unit(Unit::Gigabyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of terabytes.
+    pub fn as_tb(&self) -> f64 {
+        self.as_unit(Unit::Terabyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of petabytes.
+    pub fn as_pb(&self) -> f64 {
+        self.as_unit(Unit::Petabyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of exabytes.
+    pub fn as_eb(&self) -> f64 {
+        self.as_unit(Unit::Exabyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of kibibytes.
+    pub fn as_kib(&self) -> f64 {
+        self.as_unit(Unit::Kibibyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of mebibytes.
+    pub fn as_mib(&self) -> f64 {
+        self.as_unit(Unit::Mebibyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of gibibytes.
+    pub fn as_gib(&self) -> f64 {
+        self.as_unit(Unit::Gibibyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of tebibytes.
+    pub fn as_tib(&self) -> f64 {
+        self.as_unit(Unit::Tebibyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of pebibytes.
+    pub fn as_pib(&self) -> f64 {
+        self.as_unit(Unit::Pebibyte)
+    }
+    #[inline]
+    #[cfg(feature = "float")]
+    /// Returns the size expressed as a (possibly fractional) number of exbibytes.
+    pub fn as_eib(&self) -> f64 {
+        self.as_unit(Unit::Exbibyte)
+    }
+}
+
 // The original `size` approach was a rust enum with each unit expressed as a different variant, but
 // that was never really a "rusty" solution and didn't actually match how size calculation was
 // handled (with each value being converted to an f64/i64 before calculating the total bytes or the
@@ -552,158 +914,158 @@ impl Size {
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_bytes() instead")]
     /// Express a size in bytes.
-    pub fn Bytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Bytes<T: IntoSize>(t: T) -> Self {
         Self::from_bytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_kibibytes() instead")]
     /// Express a size in kibibytes. Actual size is 2^10 \* the value.
-    pub fn Kibibytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Kibibytes<T: IntoSize>(t: T) -> Self {
         Self::from_kibibytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_kilobytes() instead")]
     /// Express a size in kilobytes. Actual size is 10^3 \* the value.
-    pub fn Kilobytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Kilobytes<T: IntoSize>(t: T) -> Self {
         Self::from_kilobytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_mebibytes() instead")]
     /// Express a size in mebibytes. Actual size is 2^20 \* the value.
-    pub fn Mebibytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Mebibytes<T: IntoSize>(t: T) -> Self {
         Self::from_mebibytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_megabytes() instead")]
     /// Express a size in megabytes. Actual size is 10^6 \* the value.
-    pub fn Megabytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Megabytes<T: IntoSize>(t: T) -> Self {
         Self::from_megabytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_gibibytes() instead")]
     /// Express a size in gibibytes. Actual size is 2^30 \* the value.
-    pub fn Gibibytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Gibibytes<T: IntoSize>(t: T) -> Self {
         Self::from_gibibytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_gigabytes() instead")]
     /// Express a size in gigabytes. Actual size is 10^9 \* the value.
-    pub fn Gigabytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Gigabytes<T: IntoSize>(t: T) -> Self {
         Self::from_gigabytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_tebibytes() instead")]
     /// Express a size in tebibytes. Actual size is 2^40 \* the value.
-    pub fn Tebibytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Tebibytes<T: IntoSize>(t: T) -> Self {
         Self::from_tebibytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_terabytes() instead")]
     /// Express a size in terabytes. Actual size is 10^12 \* the value.
-    pub fn Terabytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Terabytes<T: IntoSize>(t: T) -> Self {
         Self::from_terabytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_pebibytes() instead")]
     /// Express a size in pebibytes. Actual size is 2^50 \* the value.
-    pub fn Pebibytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Pebibytes<T: IntoSize>(t: T) -> Self {
         Self::from_pebibytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_petabytes() instead")]
     /// Express a size in petabytes. Actual size is 10^15 \* the value.
-    pub fn Petabytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Petabytes<T: IntoSize>(t: T) -> Self {
         Self::from_petabytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_exbibytes() instead")]
     /// Express a size in exbibytes. Actual size is 2^60 \* the value.
-    pub fn Exbibytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Exbibytes<T: IntoSize>(t: T) -> Self {
         Self::from_exbibytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_exabytes() instead")]
     /// Express a size in exabytes. Actual size is 10^18 \* the value.
-    pub fn Exabytes<T: AsIntermediate>(t: T) -> Self {
+    pub fn Exabytes<T: IntoSize>(t: T) -> Self {
         Self::from_exabytes(t)
     }
 
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_bytes() instead")]
     /// Express a size in bytes, as a shortcut for using [`Size::Bytes`].
-    pub fn B<T: AsIntermediate>(t: T) -> Self {
+    pub fn B<T: IntoSize>(t: T) -> Self {
         Self::from_bytes(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_kib() instead")]
     /// Express a size in kibibytes, as a shortcut for using [`Size::Kibibytes`].
-    pub fn KiB<T: AsIntermediate>(t: T) -> Self {
+    pub fn KiB<T: IntoSize>(t: T) -> Self {
         Self::from_kib(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_kb() instead")]
     /// Express a size in kilobytes, as a shortcut for using [`Size::Kilobytes`].
-    pub fn KB<T: AsIntermediate>(t: T) -> Self {
+    pub fn KB<T: IntoSize>(t: T) -> Self {
         Self::from_kb(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_mib() instead")]
     /// Express a size in mebibytes, as a shortcut for using [`Size::Mebibytes`].
-    pub fn MiB<T: AsIntermediate>(t: T) -> Self {
+    pub fn MiB<T: IntoSize>(t: T) -> Self {
         Self::from_mib(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_mb() instead")]
     /// Express a size in megabytes, as a shortcut for using [`Size::Megabytes`].
-    pub fn MB<T: AsIntermediate>(t: T) -> Self {
+    pub fn MB<T: IntoSize>(t: T) -> Self {
         Self::from_mb(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_gib() instead")]
     /// Express a size in gibibytes, as a shortcut for using [`Size::Gibibytes`].
-    pub fn GiB<T: AsIntermediate>(t: T) -> Self {
+    pub fn GiB<T: IntoSize>(t: T) -> Self {
         Self::from_gib(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_gb() instead")]
     /// Express a size in gigabytes, as a shortcut for using [`Size::Gigabytes`].
-    pub fn GB<T: AsIntermediate>(t: T) -> Self {
+    pub fn GB<T: IntoSize>(t: T) -> Self {
         Self::from_gb(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_tib() instead")]
     /// Express a size in tebibytes, as a shortcut for using [`Size::Tebibytes`].
-    pub fn TiB<T: AsIntermediate>(t: T) -> Self {
+    pub fn TiB<T: IntoSize>(t: T) -> Self {
         Self::from_tib(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_tb() instead")]
     /// Express a size in terabytes, as a shortcut for using [`Size::Terabytes`].
-    pub fn TB<T: AsIntermediate>(t: T) -> Self {
+    pub fn TB<T: IntoSize>(t: T) -> Self {
         Self::from_tb(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_pib() instead")]
     /// Express a size in pebibytes, as a shortcut for using [`Size::Pebibytes`].
-    pub fn PiB<T: AsIntermediate>(t: T) -> Self {
+    pub fn PiB<T: IntoSize>(t: T) -> Self {
         Self::from_pib(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_pb() instead")]
     /// Express a size in petabytes, as a shortcut for using [`Size::Petabytes`].
-    pub fn PB<T: AsIntermediate>(t: T) -> Self {
+    pub fn PB<T: IntoSize>(t: T) -> Self {
         Self::from_pb(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_eib() instead")]
     /// Express a size in exbibytes, as a shortcut for using [`Size::Exbibytes`].
-    pub fn EiB<T: AsIntermediate>(t: T) -> Self {
+    pub fn EiB<T: IntoSize>(t: T) -> Self {
         Self::from_eib(t)
     }
     #[inline]
     #[deprecated(since = "0.3.0", note = "Use Size::from_eb() instead")]
     /// Express a size in exabytes, as a shortcut for using [`Size::Exabytes`].
-    pub fn EB<T: AsIntermediate>(t: T) -> Self {
+    pub fn EB<T: IntoSize>(t: T) -> Self {
         Self::from_eb(t)
     }
 }
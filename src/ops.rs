@@ -7,8 +7,8 @@
 //! fine.
 //!
 //! Some examples of supported mathematical operations:
-#![cfg_attr(not(feature = "std"), doc = "```ignore")]
-#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(all(feature = "std", feature = "float")), doc = "```ignore")]
+#![cfg_attr(all(feature = "std", feature = "float"), doc = "```")]
 //! use size::Size;
 //!
 //! // Perform scalar multiplication/division on a `Size`
@@ -27,18 +27,33 @@
 //!
 //! Some other things you cannot do are multiply/divide two sizes (did you mean to multiply one size
 //! by a scalar value instead?), add/subtract scalar values from sizes (you can call `size.bytes()`
-//! then do all the scalar math you like, however), or perform mathematical operations that exceed
-//! the bounds of the intermediate type (`f64` by default or `i64` if `no_std` mode is used).
+//! then do all the scalar math you like, however).
 //!
-//! A current limitation of this crate that may be revisited at a later date is that mathematical
-//! operations (or textual representation, for that matter) of that result in a size that exceeds
-//! the bounds of an `i64` are not supported (i.e. they will not be promoted to a
-//! floating-point-backed `Size` instance) and will panic in debug mode or silently fail with
-//! undefined results in release mode.
+//! Any scalar implementing [`IntoSize`] may be used to multiply or divide a size, including the
+//! `NonZero*` and `Wrapping` integer types and your own types. Integral scalars use exact integer
+//! math (dividing by an integral zero panics, as integer division does), while floating-point
+//! scalars are only accepted when floating-point support is enabled. Products and quotients that
+//! do not fit in a `Size` saturate at [`Size::MIN`] or [`Size::MAX`].
+//!
+//! Addition and subtraction of sizes (including via [`Sum`]), on the other hand, are not promoted
+//! to a wider type when the result exceeds the bounds of an `i64`: like the underlying integer
+//! operations, they panic in debug mode and wrap around in release mode. Use
+//! [`Size::checked_add()`], [`Size::checked_sub()`], or [`Size::checked_sum()`] to detect such
+//! overflow, or the saturating and wrapping variants (e.g. [`Size::saturating_add()`] or
+//! [`Size::wrapping_sub()`]) to control it:
+//! ```
+//! use size::Size;
+//!
+//! let quota = Size::from_gib(4);
+//! assert_eq!(quota.checked_add(Size::from_mib(512)), Some(Size::from_mib(4608)));
+//! assert_eq!(Size::MAX.checked_add(quota), None);
+//! assert_eq!(Size::MAX.saturating_add(quota), Size::MAX);
+//! ```
 
-use crate::{AsIntermediate, Intermediate, Size};
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::{saturate, IntoSize, Size};
+use core::borrow::Borrow;
 use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 impl Add<Size> for Size {
     type Output = Size;
@@ -118,23 +133,23 @@ impl<'a> Sum<&'a Size> for Size {
 
 impl<T> Mul<T> for Size
 where
-    T: AsIntermediate,
+    T: IntoSize,
 {
     type Output = Size;
 
     fn mul(self, other: T) -> Self::Output {
-        Size::from_bytes((self.bytes() as Intermediate * other.as_()) as i64)
+        Size::from_const(saturate(other.into_bytes(self.bytes() as i128)))
     }
 }
 
 impl<T> Mul<T> for &Size
 where
-    T: AsIntermediate,
+    T: IntoSize,
 {
     type Output = Size;
 
     fn mul(self, other: T) -> Self::Output {
-        Size::from_bytes((self.bytes() as Intermediate * other.as_()) as i64)
+        Size::from_const(saturate(other.into_bytes(self.bytes() as i128)))
     }
 }
 
@@ -144,7 +159,7 @@ macro_rules! impl_mul {
             type Output = Size;
 
             fn mul(self, other: Size) -> Self::Output {
-                Size::from_bytes((self.as_() * other.bytes() as Intermediate) as i64)
+                Size::from_const(saturate(self.into_bytes(other.bytes() as i128)))
             }
         }
 
@@ -152,35 +167,35 @@ macro_rules! impl_mul {
             type Output = Size;
 
             fn mul(self, other: &Size) -> Self::Output {
-                Size::from_bytes((self.as_() * other.bytes() as Intermediate) as i64)
+                Size::from_const(saturate(self.into_bytes(other.bytes() as i128)))
             }
         }
     };
 }
 
 impl_mul!(i64);
-#[cfg(feature = "std")]
+#[cfg(feature = "float")]
 impl_mul!(f64);
 
 impl<T> Div<T> for Size
 where
-    T: AsIntermediate,
+    T: IntoSize,
 {
     type Output = Size;
 
     fn div(self, other: T) -> Self::Output {
-        Size::from_bytes((self.bytes() as Intermediate / other.as_()) as i64)
+        Size::from_const(saturate(other.divide_bytes(self.bytes() as i128)))
     }
 }
 
 impl<T> Div<T> for &Size
 where
-    T: AsIntermediate,
+    T: IntoSize,
 {
     type Output = Size;
 
     fn div(self, other: T) -> Self::Output {
-        Size::from_bytes((self.bytes() as Intermediate / other.as_()) as i64)
+        Size::from_const(saturate(other.divide_bytes(self.bytes() as i128)))
     }
 }
 
@@ -212,7 +227,7 @@ impl SubAssign<&Size> for Size {
 
 impl<T> MulAssign<T> for Size
 where
-    T: AsIntermediate,
+    T: IntoSize,
 {
     fn mul_assign(&mut self, other: T) {
         *self = *self * other;
@@ -221,9 +236,125 @@ where
 
 impl<T> DivAssign<T> for Size
 where
-    T: AsIntermediate,
+    T: IntoSize,
 {
     fn div_assign(&mut self, other: T) {
         *self = *self / other;
     }
 }
+
+/* Checked, saturating, and wrapping integral operations */
+
+impl Size {
+    /// Checked addition of two sizes. Returns `None` if the result would overflow.
+    pub const fn checked_add(self, other: Size) -> Option<Size> {
+        match self.bytes.checked_add(other.bytes) {
+            Some(bytes) => Some(Size::from_const(bytes)),
+            None => None,
+        }
+    }
+
+    /// Checked subtraction of two sizes. Returns `None` if the result would overflow.
+    pub const fn checked_sub(self, other: Size) -> Option<Size> {
+        match self.bytes.checked_sub(other.bytes) {
+            Some(bytes) => Some(Size::from_const(bytes)),
+            None => None,
+        }
+    }
+
+    /// Checked multiplication of a size by an integral scalar. Returns `None` if the result would
+    /// overflow.
+    pub const fn checked_mul(self, scalar: i64) -> Option<Size> {
+        match self.bytes.checked_mul(scalar) {
+            Some(bytes) => Some(Size::from_const(bytes)),
+            None => None,
+        }
+    }
+
+    /// Checked division of a size by an integral scalar. Returns `None` if `scalar` is zero or if
+    /// the result would overflow (i.e. `Size::MIN / -1`).
+    pub const fn checked_div(self, scalar: i64) -> Option<Size> {
+        match self.bytes.checked_div(scalar) {
+            Some(bytes) => Some(Size::from_const(bytes)),
+            None => None,
+        }
+    }
+
+    /// Saturating addition of two sizes, clamping the result to [`Size::MIN`] or [`Size::MAX`]
+    /// instead of overflowing.
+    pub const fn saturating_add(self, other: Size) -> Size {
+        Size::from_const(self.bytes.saturating_add(other.bytes))
+    }
+
+    /// Saturating subtraction of two sizes, clamping the result to [`Size::MIN`] or [`Size::MAX`]
+    /// instead of overflowing.
+    pub const fn saturating_sub(self, other: Size) -> Size {
+        Size::from_const(self.bytes.saturating_sub(other.bytes))
+    }
+
+    /// Saturating multiplication of a size by an integral scalar, clamping the result to
+    /// [`Size::MIN`] or [`Size::MAX`] instead of overflowing.
+    pub const fn saturating_mul(self, scalar: i64) -> Size {
+        Size::from_const(self.bytes.saturating_mul(scalar))
+    }
+
+    /// Saturating division of a size by an integral scalar, clamping the result to [`Size::MAX`]
+    /// instead of overflowing (i.e. `Size::MIN / -1`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalar` is zero.
+    pub const fn saturating_div(self, scalar: i64) -> Size {
+        Size::from_const(self.bytes.saturating_div(scalar))
+    }
+
+    /// Wrapping (modular) addition of two sizes, wrapping around at the boundary of the underlying
+    /// `i64` byte count.
+    pub const fn wrapping_add(self, other: Size) -> Size {
+        Size::from_const(self.bytes.wrapping_add(other.bytes))
+    }
+
+    /// Wrapping (modular) subtraction of two sizes, wrapping around at the boundary of the
+    /// underlying `i64` byte count.
+    pub const fn wrapping_sub(self, other: Size) -> Size {
+        Size::from_const(self.bytes.wrapping_sub(other.bytes))
+    }
+
+    /// Wrapping (modular) multiplication of a size by an integral scalar, wrapping around at the
+    /// boundary of the underlying `i64` byte count.
+    pub const fn wrapping_mul(self, scalar: i64) -> Size {
+        Size::from_const(self.bytes.wrapping_mul(scalar))
+    }
+
+    /// Wrapping (modular) division of a size by an integral scalar. The only case where wrapping
+    /// can occur is `Size::MIN / -1`, which yields `Size::MIN`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalar` is zero.
+    pub const fn wrapping_div(self, scalar: i64) -> Size {
+        Size::from_const(self.bytes.wrapping_div(scalar))
+    }
+
+    /// Sums the sizes yielded by an iterator (of either `Size` or `&Size` values), returning `None`
+    /// if the total overflows at any point rather than panicking or returning an undefined result
+    /// as [`Sum`] would.
+    ///
+    /// ```
+    /// use size::Size;
+    ///
+    /// let sizes = [Size::from_gib(2), Size::from_gib(3)];
+    /// assert_eq!(Size::checked_sum(&sizes), Some(Size::from_gib(5)));
+    ///
+    /// let sizes = [Size::MAX, Size::from_bytes(1)];
+    /// assert_eq!(Size::checked_sum(sizes.iter()), None);
+    /// ```
+    pub fn checked_sum<I>(iter: I) -> Option<Size>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Size>,
+    {
+        iter.into_iter()
+            .try_fold(Size::ZERO, |total, size| total.checked_add(*size.borrow()))
+    }
+}
@@ -0,0 +1,260 @@
+//! Explicit control over how fractional values are rounded, both when creating a [`Size`] from a
+//! floating-point value and when formatting a size as text.
+//!
+//! The generic constructors (e.g. [`Size::from_mb()`]) always truncate any fractional bytes toward
+//! zero, the same as an `as i64` cast would. [`Size::from_f64_with()`] can be used instead when
+//! a different [`RoundingMode`] is required, or when a fractional number of bytes should be treated
+//! as an error. That constructor (and [`FromFloatError`]) are only available with the `float`
+//! feature.
+//!
+//! The same [`RoundingMode`] is used by [`SizeFormatter::with_rounding()`] to round the last digit
+//! of a formatted size.
+//!
+//! [`Size`]: crate::Size
+//! [`Size::from_mb()`]: crate::Size::from_mb()
+//! [`SizeFormatter::with_rounding()`]: crate::SizeFormatter::with_rounding()
+
+#[cfg(feature = "float")]
+use self::math::{abs, ceil, floor, round, trunc};
+#[cfg(feature = "float")]
+use crate::{Size, Unit};
+
+/// The float operations used below: the inherent `f64` methods with `std`, or their `libm`
+/// equivalents in `no_std` builds.
+#[cfg(all(feature = "float", feature = "std"))]
+mod math {
+    pub(super) fn abs(x: f64) -> f64 {
+        x.abs()
+    }
+
+    pub(super) fn ceil(x: f64) -> f64 {
+        x.ceil()
+    }
+
+    pub(super) fn floor(x: f64) -> f64 {
+        x.floor()
+    }
+
+    pub(super) fn round(x: f64) -> f64 {
+        x.round()
+    }
+
+    pub(super) fn trunc(x: f64) -> f64 {
+        x.trunc()
+    }
+}
+
+#[cfg(all(feature = "float", not(feature = "std")))]
+mod math {
+    pub(super) use libm::{ceil, fabs as abs, floor, round, trunc};
+}
+
+/// The strategies available for resolving a fractional number of bytes into a whole number of bytes
+/// when creating a [`Size`] via [`Size::from_f64_with()`], or for rounding the last digit of a size
+/// formatted via [`SizeFormatter::with_rounding()`].
+///
+/// When formatting, [`RoundingMode::NearestEven`] is the default for all styles other than
+/// [`Style::Compact`], which rounds up ([`RoundingMode::Ceil`]) by default. [`RoundingMode::Strict`]
+/// has no meaning for a formatted size, which is rounded with [`RoundingMode::NearestEven`] instead.
+///
+/// [`Size`]: crate::Size
+/// [`SizeFormatter::with_rounding()`]: crate::SizeFormatter::with_rounding()
+/// [`Style::Compact`]: crate::Style::Compact
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Discard any fractional bytes, rounding toward zero. This matches the behavior of the generic
+    /// `Size::from_xxx()` constructors.
+    Truncate,
+    /// Round toward negative infinity, e.g. for sizes that must never be over-reported (such as
+    /// free space).
+    Floor,
+    /// Round toward positive infinity, e.g. when sizing an allocation that must be large enough, or
+    /// so that disk usage is never under-reported (as `du` does).
+    Ceil,
+    /// Round to the nearest whole byte, breaking ties by rounding to the even neighbor (banker's
+    /// rounding), e.g. for billing.
+    NearestEven,
+    /// Round to the nearest whole byte, breaking ties by rounding away from zero (as `f64::round()`
+    /// does).
+    Nearest,
+    /// Do not round at all: any fractional number of bytes is treated as an error.
+    Strict,
+}
+
+/// Represents an error creating a `Size` from a floating-point value via
+/// [`Size::from_f64_with()`].
+#[cfg(feature = "float")]
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Clone, Copy, Eq)]
+pub enum FromFloatError {
+    /// The value did not amount to a whole number of bytes and [`RoundingMode::Strict`] was used.
+    Inexact,
+    /// The value was NaN, infinite, or (after rounding) outside the range of a `Size`.
+    OutOfRange,
+}
+
+#[cfg(all(feature = "float", feature = "std"))]
+impl std::error::Error for FromFloatError {}
+#[cfg(feature = "float")]
+impl core::fmt::Display for FromFloatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FromFloatError::Inexact => f.write_str("Size is not a whole number of bytes"),
+            FromFloatError::OutOfRange => f.write_str("Size is out of range"),
+        }
+    }
+}
+
+/// Rounds `value` to the nearest integer, rounding ties to the even neighbor.
+#[cfg(feature = "float")]
+fn round_ties_even(value: f64) -> f64 {
+    let rounded = round(value);
+    if abs(value - trunc(value)) == 0.5 {
+        // `f64::round()` rounds ties away from zero, which is odd half of the time.
+        2.0 * round(value / 2.0)
+    } else {
+        rounded
+    }
+}
+
+#[cfg(feature = "float")]
+impl Size {
+    /// Create a `Size` from a floating-point `value` expressed in terms of `unit` (e.g.
+    /// [`Unit::MiB`]), using the provided [`RoundingMode`] to resolve any fractional bytes.
+    ///
+    /// Unlike the generic constructors, which silently clamp, this returns an error if `value` is
+    /// not a finite number or if the result does not fit in a `Size`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use size::{FromFloatError, RoundingMode, Size, Unit};
+    ///
+    /// let size = Size::from_f64_with(1.0000005, Unit::MB, RoundingMode::Ceil);
+    /// assert_eq!(size, Ok(Size::from_bytes(1_000_001)));
+    ///
+    /// let size = Size::from_f64_with(2.5, Unit::B, RoundingMode::NearestEven);
+    /// assert_eq!(size, Ok(Size::from_bytes(2)));
+    ///
+    /// let size = Size::from_f64_with(1.5, Unit::B, RoundingMode::Strict);
+    /// assert_eq!(size, Err(FromFloatError::Inexact));
+    /// ```
+    pub fn from_f64_with(
+        value: f64,
+        unit: Unit,
+        mode: RoundingMode,
+    ) -> Result<Size, FromFloatError> {
+        let bytes = value * unit.bytes_wide() as f64;
+        let bytes = match mode {
+            RoundingMode::Truncate => trunc(bytes),
+            RoundingMode::Floor => floor(bytes),
+            RoundingMode::Ceil => ceil(bytes),
+            RoundingMode::NearestEven => round_ties_even(bytes),
+            RoundingMode::Nearest => round(bytes),
+            RoundingMode::Strict if bytes - trunc(bytes) != 0.0 && bytes.is_finite() => {
+                return Err(FromFloatError::Inexact)
+            }
+            RoundingMode::Strict => bytes,
+        };
+
+        // i64::MIN is exactly representable as an f64 but i64::MAX rounds up to 2^63.
+        if bytes.is_nan() || bytes < i64::MIN as f64 || bytes >= i64::MAX as f64 {
+            return Err(FromFloatError::OutOfRange);
+        }
+
+        Ok(Size::from_const(bytes as i64))
+    }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directed_rounding() {
+        let tests = [
+            (RoundingMode::Truncate, 1_000_000, -1_000_000),
+            (RoundingMode::Floor, 1_000_000, -1_000_001),
+            (RoundingMode::Ceil, 1_000_001, -1_000_000),
+        ];
+
+        for (mode, positive, negative) in tests {
+            assert_eq!(
+                Size::from_f64_with(1.0000005, Unit::MB, mode),
+                Ok(Size::from_bytes(positive))
+            );
+            assert_eq!(
+                Size::from_f64_with(-1.0000005, Unit::MB, mode),
+                Ok(Size::from_bytes(negative))
+            );
+        }
+    }
+
+    #[test]
+    fn nearest_even() {
+        let tests = [
+            (0.5, 0),
+            (1.5, 2),
+            (2.5, 2),
+            (2.51, 3),
+            (-0.5, 0),
+            (-1.5, -2),
+            (-2.4, -2),
+        ];
+
+        for (value, expected) in tests {
+            let size = Size::from_f64_with(value, Unit::B, RoundingMode::NearestEven);
+            assert_eq!(size, Ok(Size::from_bytes(expected)), "rounding {}", value);
+        }
+    }
+
+    #[test]
+    fn nearest() {
+        let tests = [
+            (0.5, 1),
+            (1.5, 2),
+            (2.5, 3),
+            (2.4, 2),
+            (-0.5, -1),
+            (-2.5, -3),
+        ];
+
+        for (value, expected) in tests {
+            let size = Size::from_f64_with(value, Unit::B, RoundingMode::Nearest);
+            assert_eq!(size, Ok(Size::from_bytes(expected)), "rounding {}", value);
+        }
+    }
+
+    #[test]
+    fn strict() {
+        assert_eq!(
+            Size::from_f64_with(1.5, Unit::KiB, RoundingMode::Strict),
+            Ok(Size::from_bytes(1536))
+        );
+        assert_eq!(
+            Size::from_f64_with(1.5, Unit::B, RoundingMode::Strict),
+            Err(FromFloatError::Inexact)
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        for mode in [
+            RoundingMode::Truncate,
+            RoundingMode::Strict,
+            RoundingMode::Ceil,
+        ] {
+            assert_eq!(
+                Size::from_f64_with(f64::NAN, Unit::KB, mode),
+                Err(FromFloatError::OutOfRange)
+            );
+            assert_eq!(
+                Size::from_f64_with(f64::INFINITY, Unit::B, mode),
+                Err(FromFloatError::OutOfRange)
+            );
+            assert_eq!(Size::from_f64_with(8.0, Unit::EiB, mode), Err(FromFloatError::OutOfRange));
+            assert_eq!(Size::from_f64_with(-8.0, Unit::EiB, mode), Ok(Size::MIN));
+        }
+    }
+}
@@ -1,7 +1,13 @@
-use crate::Size;
+use crate::{ByteCount, Size};
+use core::fmt;
 use serde::de;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::fmt;
+
+// The test harness always links `std`, even when the crate itself is built `no_std`.
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+#[cfg(all(test, not(feature = "std")))]
+use std::string::ToString;
 
 struct SizeVisitor;
 
@@ -24,7 +30,7 @@ impl<'de> de::Visitor<'de> for SizeVisitor {
         E: de::Error,
     {
         if value > i64::MAX as u64 {
-            Err(E::custom(format!("u64 size {} is out of range", value)))
+            Err(E::custom(format_args!("u64 size {} is out of range", value)))
         } else {
             Ok(Size {
                 bytes: value as i64,
@@ -36,8 +42,8 @@ impl<'de> de::Visitor<'de> for SizeVisitor {
     where
         E: de::Error,
     {
-        if value.is_infinite() || value > i64::MAX as f32 || value < i64::MIN as f32 {
-            Err(E::custom(format!("f32 size {} is out of range", value)))
+        if !value.is_finite() || value > i64::MAX as f32 || value < i64::MIN as f32 {
+            Err(E::custom(format_args!("f32 size {} is out of range", value)))
         } else {
             Ok(Size {
                 bytes: value as i64,
@@ -49,8 +55,8 @@ impl<'de> de::Visitor<'de> for SizeVisitor {
     where
         E: de::Error,
     {
-        if value.is_infinite() || value > i64::MAX as f64 || value < i64::MIN as f64 {
-            Err(E::custom(format!("f64 size {} is out of range", value)))
+        if !value.is_finite() || value > i64::MAX as f64 || value < i64::MIN as f64 {
+            Err(E::custom(format_args!("f64 size {} is out of range", value)))
         } else {
             Ok(Size {
                 bytes: value as i64,
@@ -62,7 +68,79 @@ impl<'de> de::Visitor<'de> for SizeVisitor {
     where
         E: de::Error,
     {
-        Size::from_str(value).map_err(|_| E::custom(format!("Invalid size: \"{value}\"")))
+        Size::from_str(value).map_err(|_| E::custom(format_args!("Invalid size: \"{value}\"")))
+    }
+}
+
+struct ByteCountVisitor;
+
+impl<'de> de::Visitor<'de> for ByteCountVisitor {
+    type Value = ByteCount;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a non-negative integer or floating point number of bytes")
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if value < 0 {
+            Err(E::custom(format_args!("i64 byte count {} is negative", value)))
+        } else {
+            Ok(ByteCount::from_const(value as u64))
+        }
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ByteCount::from_const(value))
+    }
+
+    fn visit_f32<E>(self, value: f32) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_f64(value as f64)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // u64::MAX rounds up to 2^64 as an f64, so that value itself is out of range.
+        if value.is_nan() || value >= u64::MAX as f64 || value <= -1.0 {
+            Err(E::custom(format_args!("f64 byte count {} is out of range", value)))
+        } else {
+            Ok(ByteCount::from_const(value as u64))
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        ByteCount::from_str(value).map_err(|_| E::custom(format_args!("Invalid size: \"{value}\"")))
+    }
+}
+
+impl Serialize for ByteCount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for ByteCount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ByteCountVisitor)
     }
 }
 
@@ -151,6 +229,53 @@ fn test_deserialize_overflow() {
     let json = r#"{"size": 2.99792458e118}"#;
     let foo: Result<Foo, _> = serde_json::from_str(json);
     assert!(foo.is_err());
-    let msg = foo.unwrap_err().to_string();
-    assert!(msg.contains("out of range"));
+    #[cfg(feature = "alloc")]
+    {
+        let msg = foo.unwrap_err().to_string();
+        assert!(msg.contains("out of range"));
+    }
+}
+
+#[test]
+fn test_byte_count_roundtrip() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+    struct Foo {
+        size: ByteCount,
+    }
+
+    let foo = Foo {
+        size: ByteCount::MAX,
+    };
+    let json = serde_json::to_string(&foo).unwrap();
+    assert_eq!(json, r#"{"size":18446744073709551615}"#);
+    assert_eq!(serde_json::from_str::<Foo>(&json).unwrap(), foo);
+
+    let foo: Foo = serde_json::from_str(r#"{"size": "12 EiB"}"#).unwrap();
+    assert_eq!(foo.size, ByteCount::from_eib(12));
+    assert!(serde_json::from_str::<Foo>(r#"{"size": -1}"#).is_err());
+}
+
+#[test]
+fn test_deserialize_nan() {
+    use serde::de::value::{Error, F32Deserializer, F64Deserializer};
+    use serde::de::IntoDeserializer;
+
+    // JSON can't represent NaN, so feed it to the visitors directly.
+    let nan: F64Deserializer<Error> = f64::NAN.into_deserializer();
+    assert!(Size::deserialize(nan).is_err());
+    let nan: F32Deserializer<Error> = f32::NAN.into_deserializer();
+    assert!(Size::deserialize(nan).is_err());
+}
+
+#[test]
+fn test_byte_count_deserialize_nan() {
+    use serde::de::value::{Error, F32Deserializer, F64Deserializer};
+    use serde::de::IntoDeserializer;
+
+    let nan: F64Deserializer<Error> = f64::NAN.into_deserializer();
+    assert!(ByteCount::deserialize(nan).is_err());
+    let nan: F32Deserializer<Error> = f32::NAN.into_deserializer();
+    assert!(ByteCount::deserialize(nan).is_err());
 }
@@ -1,7 +1,9 @@
-#![cfg(feature = "std")]
+#![cfg(all(feature = "std", feature = "float"))]
 #![allow(deprecated)]
+// The reference operands below deliberately exercise the `impl Op<&Size> for &Size` variants.
+#![allow(clippy::op_ref)]
 
-use crate::Size;
+use crate::{Base, Size, Unit};
 
 #[test]
 fn unit_tests() {
@@ -17,6 +19,29 @@ fn negative_tests() {
     assert_eq!("-2.00 MiB", format!("{}", Size::from_kibibytes(-2048)));
 }
 
+#[test]
+/// Sizes used to be formatted by dividing through an `f64`, which rounded decimal ties up or down
+/// depending on their binary representation. The exact quotient is now rounded half-to-even, which
+/// changes the output for some base-10 sizes. This pins the new outputs.
+fn exact_tie_rounding() {
+    let base10 = |bytes: i64| Size::from_bytes(bytes).format().with_base(Base::Base10).to_string();
+
+    assert_eq!(base10(1015), "1.02 KB"); // was "1.01 KB"
+    assert_eq!(base10(1035), "1.04 KB"); // was "1.03 KB"
+    assert_eq!(base10(1145), "1.14 KB"); // was "1.15 KB"
+    assert_eq!(base10(1165), "1.16 KB"); // was "1.17 KB"
+    assert_eq!(base10(10_350), "10.4 KB"); // was "10.3 KB"
+
+    // Ties that an `f64` happened to round to even are unchanged, as are all non-ties.
+    assert_eq!(base10(1025), "1.02 KB");
+    assert_eq!(base10(10_250), "10.2 KB");
+    assert_eq!(base10(12_345), "12.3 KB");
+
+    // Base-2 ties are exact in an `f64` as well, and were already rounded half-to-even.
+    assert_eq!(format!("{}", Size::from_bytes(1152)), "1.12 KiB");
+    assert_eq!(format!("{}", Size::from_bytes(1664)), "1.62 KiB");
+}
+
 #[test]
 fn integral_limits() {
     assert_eq!("8 EiB", format!("{}", Size::from_bytes(i64::MAX)));
@@ -30,6 +55,21 @@ fn integral_limits() {
     assert_eq!("0 bytes", format!("{}", Size::Bytes(u64::MIN)));
 }
 
+#[test]
+/// Integral values must never pass through the (lossy above 2^53) `f64` intermediate.
+fn integral_precision() {
+    assert_eq!(Size::from_pib(7).bytes(), 7 << 50);
+    assert_eq!(Size::from_bytes((1_i64 << 53) + 1).bytes(), (1 << 53) + 1);
+    assert_eq!(Size::from_bytes(i64::MAX - 1).bytes(), i64::MAX - 1);
+    assert_eq!(Size::from_bytes(u64::MAX - 1).bytes(), i64::MAX);
+    assert_eq!(
+        Size::from_kb(4_611_686_018_427_389_i64).bytes(),
+        4_611_686_018_427_389_000
+    );
+    assert_eq!(Size::from_eb(-9).bytes(), -9_000_000_000_000_000_000);
+    assert_eq!(Size::from_eb(-10).bytes(), i64::MIN);
+}
+
 #[test]
 fn float_limits() {
     assert_eq!("8 EiB", format!("{}", Size::from_kib(f64::MAX)));
@@ -185,3 +225,26 @@ fn size_div_assign_f64() {
     size /= 13f64;
     assert_eq!(size.bytes(), 923076923);
 }
+
+#[test]
+fn unit_conversion() {
+    let size = Size::from_mib(20) + Size::from_kib(512);
+    assert_eq!(size.as_mib(), 20.5);
+    assert_eq!(size.as_kib(), 20_992.0);
+    assert_eq!(size.as_gib(), 20.5 / 1024.0);
+    assert_eq!(size.as_kb(), 20_992.0 * 1.024);
+    assert_eq!(Size::from_tb(-3).as_gb(), -3000.0);
+
+    assert_eq!(size.div_rem_unit(Unit::MiB), (20, Size::from_kib(512)));
+    assert_eq!(size.div_rem_unit(Unit::Byte), (size.bytes(), Size::ZERO));
+    assert_eq!(Size::MAX.div_rem_unit(Unit::EiB), (7, Size::MAX - Size::from_eib(7)));
+}
+
+#[test]
+fn size_ext_floats() {
+    use crate::SizeExt;
+
+    assert_eq!(1.5.gb(), Size::from_mb(1500));
+    assert_eq!(0.5_f32.kib(), Size::from_bytes(512));
+    assert_eq!(2.5.bytes(), Size::from_bytes(2));
+}
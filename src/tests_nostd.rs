@@ -1,6 +1,7 @@
 #![allow(deprecated)]
 
-use crate::Size;
+use crate::fmt::tests::fmt;
+use crate::{Size, SizeExt};
 
 #[test]
 fn nostd_add() {
@@ -71,3 +72,160 @@ fn nostd_neg_sub_assign() {
     s1 -= s2;
     assert_eq!(s1.bytes(), Size::from_kib(-12).bytes());
 }
+
+#[test]
+fn nostd_checked_ops() {
+    let size = Size::from_kib(12);
+    assert_eq!(size.checked_add(Size::from_kib(4)), Some(Size::from_kib(16)));
+    assert_eq!(size.checked_sub(Size::from_kib(16)), Some(Size::from_kib(-4)));
+    assert_eq!(size.checked_mul(3), Some(Size::from_kib(36)));
+    assert_eq!(size.checked_div(4), Some(Size::from_kib(3)));
+
+    assert_eq!(Size::MAX.checked_add(Size::from_bytes(1)), None);
+    assert_eq!(Size::MIN.checked_sub(Size::from_bytes(1)), None);
+    assert_eq!(Size::MAX.checked_mul(2), None);
+    assert_eq!(size.checked_div(0), None);
+    assert_eq!(Size::MIN.checked_div(-1), None);
+}
+
+#[test]
+fn nostd_saturating_ops() {
+    assert_eq!(Size::MAX.saturating_add(Size::from_kib(1)), Size::MAX);
+    assert_eq!(Size::MIN.saturating_sub(Size::from_kib(1)), Size::MIN);
+    assert_eq!(Size::MAX.saturating_mul(-2), Size::MIN);
+    assert_eq!(Size::MIN.saturating_div(-1), Size::MAX);
+    assert_eq!(Size::from_kib(1).saturating_mul(2), Size::from_kib(2));
+}
+
+#[test]
+fn nostd_wrapping_ops() {
+    assert_eq!(Size::MAX.wrapping_add(Size::from_bytes(1)), Size::MIN);
+    assert_eq!(Size::MIN.wrapping_sub(Size::from_bytes(1)), Size::MAX);
+    assert_eq!(Size::MAX.wrapping_mul(2), Size::from_bytes(-2));
+    assert_eq!(Size::MIN.wrapping_div(-1), Size::MIN);
+}
+
+#[test]
+fn nostd_checked_sum() {
+    let sizes = [Size::from_kib(12), Size::from_kib(24)];
+    assert_eq!(Size::checked_sum(sizes.iter()), Some(Size::from_kib(36)));
+    assert_eq!(Size::checked_sum(sizes), Some(Size::from_kib(36)));
+    assert_eq!(Size::checked_sum([Size::MAX, Size::from_bytes(1)]), None);
+    assert_eq!(Size::checked_sum(core::iter::empty::<Size>()), Some(Size::ZERO));
+}
+
+#[test]
+fn nostd_const_constructors() {
+    const LIMIT: Size = Size::from_mib_const(512);
+    const TOTAL: Size = match LIMIT.checked_add(Size::from_kib_const(512)) {
+        Some(total) => total,
+        None => panic!("overflow"),
+    };
+    const _: () = assert!(LIMIT.cmp_const(&TOTAL).is_lt());
+
+    assert_eq!(LIMIT, Size::from_mib(512));
+    assert_eq!(TOTAL, Size::from_kib(524_800));
+    assert_eq!(Size::from_gb_const(3), Size::from_gb(3));
+    assert_eq!(Size::from_eib_const(-9), Size::MIN);
+    assert_eq!(Size::from_eib_const(8), Size::MAX);
+}
+
+#[test]
+fn nostd_const_min_max() {
+    const SMALL: Size = Size::from_kb_const(1);
+    const LARGE: Size = Size::from_kib_const(1);
+
+    const _: () = assert!(SMALL.min_const(LARGE).eq_const(&SMALL));
+
+    assert_eq!(SMALL.min_const(LARGE), SMALL);
+    assert_eq!(LARGE.min_const(SMALL), SMALL);
+    assert_eq!(SMALL.max_const(LARGE), LARGE);
+    assert_eq!(LARGE.max_const(SMALL), LARGE);
+    assert_eq!(SMALL.min_const(LARGE), SMALL.min(LARGE));
+    assert_eq!(SMALL.max_const(LARGE), SMALL.max(LARGE));
+    assert_eq!(SMALL.cmp_const(&LARGE), SMALL.cmp(&LARGE));
+    assert_eq!(LARGE.cmp_const(&SMALL), LARGE.cmp(&SMALL));
+    assert_eq!(SMALL.cmp_const(&SMALL), SMALL.cmp(&SMALL));
+
+    for (a, b) in [(SMALL, LARGE), (LARGE, SMALL), (SMALL, SMALL)] {
+        assert_eq!(a.eq_const(&b), a == b);
+        assert_eq!(a.lt_const(&b), a < b);
+        assert_eq!(a.le_const(&b), a <= b);
+        assert_eq!(a.gt_const(&b), a > b);
+        assert_eq!(a.ge_const(&b), a >= b);
+    }
+}
+
+#[test]
+fn nostd_size_ext() {
+    assert_eq!(64.mib() + 512.kib(), Size::from_mib(64) + Size::from_kib(512));
+    assert_eq!(12_u8.bytes(), Size::from_bytes(12));
+    assert_eq!(3_u64.gb(), Size::from_gb(3));
+    assert_eq!((-2_i16).tib(), Size::from_tib(-2));
+    assert_eq!(8.eib(), Size::MAX);
+}
+
+#[test]
+fn nostd_format() {
+    use crate::{Base, Style};
+
+    let size = Size::from_bytes(12_800);
+    assert_eq!(fmt(size), "12.5 KiB");
+    assert_eq!(fmt(Size::from_bytes(1)), "1 byte");
+    assert_eq!(fmt(Size::MIN), "-8 EiB");
+
+    let formatted = size
+        .format()
+        .with_base(Base::Base10)
+        .with_style(Style::Full);
+    assert_eq!(fmt(formatted), "12.8 Kilobytes");
+    let formatted = size.format().with_scale(Some(3));
+    assert_eq!(fmt(formatted), "12.500 KiB");
+}
+
+#[test]
+fn nostd_format_rounding() {
+    let format = |bytes: i64, scale| fmt(Size::from_bytes(bytes).format().with_scale(scale));
+
+    // Exact ties are rounded to an even last digit
+    assert_eq!(format(1152, Some(2)), "1.12 KiB"); // 1.125
+    assert_eq!(format(1664, Some(2)), "1.62 KiB"); // 1.625
+    assert_eq!(format(1408, Some(2)), "1.38 KiB"); // 1.375
+    assert_eq!(format(1536, Some(0)), "2 KiB"); // 1.5
+    assert_eq!(format(2560, Some(0)), "2 KiB"); // 2.5
+
+    // Anything past the tie is rounded up, carrying as needed
+    assert_eq!(format(1153, Some(2)), "1.13 KiB");
+    assert_eq!(format(10_239, Some(2)), "10.00 KiB");
+    // Digits past the exact quotient are zero
+    assert_eq!(format(1025, Some(12)), "1.000976562500 KiB");
+}
+
+#[test]
+fn nostd_parse() {
+    assert_eq!("64 MiB".parse(), Ok(Size::from_mib(64)));
+    assert_eq!(Size::from_str("1.5kb"), Ok(Size::from_bytes(1500)));
+    assert_eq!(Size::from_str("-2.5e3 KiB"), Ok(Size::from_kib(-2500)));
+    assert_eq!(Size::from_str("9 EiB"), Ok(Size::MAX));
+    assert!(Size::from_str("64 M").is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nostd_alloc_format() {
+    use crate::SizeFormatter;
+
+    assert_eq!(SizeFormatter::new().format(12_800), "12.5 KiB");
+    assert_eq!(SizeFormatter::new().with_scale(Some(0)).format(-1536), "-2 KiB");
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn nostd_floats() {
+    use crate::{RoundingMode, Unit};
+
+    assert_eq!(Size::from_kib(1.5), Size::from_bytes(1536));
+    assert_eq!(Size::from_kib(2) * 0.5, Size::from_kib(1));
+    let size = Size::from_f64_with(2.5, Unit::Byte, RoundingMode::NearestEven);
+    assert_eq!(size, Ok(Size::from_bytes(2)));
+}
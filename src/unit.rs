@@ -0,0 +1,507 @@
+//! The `unit` module contains the [`Unit`] and [`Base`] types, describing the units in which a size
+//! may be expressed and the bases those units belong to.
+//!
+//! Both types are available in `no_std` mode and are re-exported at the crate level as `size::Unit`
+//! and `size::Base`.
+//!
+//! ```
+//! use size::{Base, Unit};
+//!
+//! assert_eq!(Unit::MiB.bytes(), 1_048_576);
+//! assert_eq!(Unit::Megabyte.short_name(), "MB");
+//! assert_eq!("gibibytes".parse(), Ok(Unit::GiB));
+//!
+//! let names: Vec<_> = Unit::iter(Base::Base10).map(Unit::short_name).collect();
+//! assert_eq!(names, ["B", "KB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB", "RB", "QB"]);
+//! ```
+
+use crate::ParseSizeError;
+use core::fmt;
+use core::str::FromStr;
+
+/// An enumeration of supported bases to use for generating textual descriptions of sizes.
+///
+/// [`Base::Base10`] is the "usual" units like "kilobyte" and "exabyte", while [`Base::Base2`] is
+/// the SI/memory units like "mebibyte" and "tebibyte", (more often referred to as "MiB" and "TiB",
+/// respectively). [`Base::Jedec`] uses the base-2 units but labels them with the names of the
+/// base-10 units, as Windows Explorer does. [`Base::Auto`] picks between base-2 and base-10 for
+/// each value formatted.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Base {
+    /// Base-2 units like "kibibyte" and "mebibyte", more often referred to via their abbreviations
+    /// ("KiB" and "MiB", respectively). Each unit is 1024 times greater than the preceding one.
+    Base2,
+    /// Base-10 units like "kilobyte" and "megabyte". Each unit is 1000 times greater than the
+    /// preceding one.
+    Base10,
+    /// Base-2 units written with the names of the base-10 units, e.g. "KB" for 1024 bytes, as
+    /// popularized by JEDEC memory standards and used by Windows Explorer and many legacy tools.
+    Jedec,
+    /// Picks the base for each value formatted: base-10 units for values that are an exact
+    /// multiple of a power of 1000 (e.g. "4 TB", even though 4,000,000,000,000 bytes are also a
+    /// multiple of 1024), otherwise base-2 units for values that are an exact multiple of a power
+    /// of 1024 (e.g. "4 KiB" or "8 GiB"), and base-10 units for all others. When iterating over
+    /// units, this behaves like [`Base::Base2`].
+    ///
+    /// Preferring base-10 trades exactness for some values that are round in both bases: exactly
+    /// 1000 MiB (1,048,576,000 bytes) is a multiple of 1000 and is written as the rounded "1.05 GB"
+    /// rather than the exact "1000 MiB".
+    Auto,
+}
+
+impl Base {
+    /// An alias for [`Base::Jedec`], the legacy convention of using base-10 unit names for base-2
+    /// multipliers.
+    #[allow(non_upper_case_globals)]
+    pub const Legacy: Base = Base::Jedec;
+
+    /// Resolves [`Base::Auto`] to the base used for a value of `bytes`, returning all other bases
+    /// unchanged. Any multiple of 1000 goes to base-10, so 1,024,000 bytes are written as
+    /// "1.02 MB".
+    // `u128::is_multiple_of()` is only available from Rust 1.87.
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    pub(crate) const fn resolve(self, bytes: u128) -> Base {
+        match self {
+            Base::Auto if bytes % 1000 != 0 && bytes % 1024 == 0 => Base::Base2,
+            Base::Auto => Base::Base10,
+            base => base,
+        }
+    }
+}
+
+/// A collection of units used to refer to sizes, for all supported bases.
+///
+/// Each unit knows its size in bytes ([`Unit::bytes()`]), the [`Base`] it belongs to, and its
+/// abbreviated and unabridged names. Abbreviated aliases such as `Unit::KiB` and `Unit::GB` are
+/// provided as associated constants for convenience.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// The basic "byte" unit, used by both base-2 and base-10 styles.
+    Byte,
+    /// The base-2 "kibibyte" unit, equal to 1024 bytes.
+    Kibibyte,
+    /// The base-10 "kilobyte" unit, equal to 1000 bytes.
+    Kilobyte,
+    /// The base-2 "mebibyte" unit, equal to 1024 kibibytes.
+    Mebibyte,
+    /// The base-10 "megabyte" unit, equal to 1000 kilobytes.
+    Megabyte,
+    /// The base-2 "gibibyte" unit, equal to 1024 mebibytes.
+    Gibibyte,
+    /// The base-10 "gigabyte" unit, equal to 1000 megabytes.
+    Gigabyte,
+    /// The base-2 "tebibyte" unit, equal to 1024 gibibytes.
+    Tebibyte,
+    /// The base-10 "terabyte" unit, equal to 1000 gigabytes.
+    Terabyte,
+    /// The base-2 "pebibyte" unit, equal to 1024 tebibytes.
+    Pebibyte,
+    /// The base-10 "petabyte" unit, equal to 1000 terabytes.
+    Petabyte,
+    /// The base-2 "exbibyte" unit, equal to 1024 pebibytes.
+    Exbibyte,
+    /// The base-10 "exabyte" unit, equal to 1000 petabytes.
+    Exabyte,
+    /// The base-2 "zebibyte" unit, equal to 1024 exbibytes.
+    Zebibyte,
+    /// The base-10 "zettabyte" unit, equal to 1000 exabytes.
+    Zettabyte,
+    /// The base-2 "yobibyte" unit, equal to 1024 zebibytes.
+    Yobibyte,
+    /// The base-10 "yottabyte" unit, equal to 1000 zettabytes.
+    Yottabyte,
+    /// The base-2 "robibyte" unit, equal to 1024 yobibytes.
+    Robibyte,
+    /// The base-10 "ronnabyte" unit, equal to 1000 yottabytes.
+    Ronnabyte,
+    /// The base-2 "quebibyte" unit, equal to 1024 robibytes.
+    Quebibyte,
+    /// The base-10 "quettabyte" unit, equal to 1000 ronnabytes.
+    Quettabyte,
+}
+
+// Abbreviated aliases for the `Unit` variants, mirroring the constants in `size::consts`.
+#[allow(non_upper_case_globals)]
+impl Unit {
+    /// Abbreviated alias for [`Unit::Byte`].
+    pub const B: Unit = Unit::Byte;
+    /// Abbreviated alias for [`Unit::Kilobyte`].
+    pub const KB: Unit = Unit::Kilobyte;
+    /// Abbreviated alias for [`Unit::Megabyte`].
+    pub const MB: Unit = Unit::Megabyte;
+    /// Abbreviated alias for [`Unit::Gigabyte`].
+    pub const GB: Unit = Unit::Gigabyte;
+    /// Abbreviated alias for [`Unit::Terabyte`].
+    pub const TB: Unit = Unit::Terabyte;
+    /// Abbreviated alias for [`Unit::Petabyte`].
+    pub const PB: Unit = Unit::Petabyte;
+    /// Abbreviated alias for [`Unit::Exabyte`].
+    pub const EB: Unit = Unit::Exabyte;
+    /// Abbreviated alias for [`Unit::Zettabyte`].
+    pub const ZB: Unit = Unit::Zettabyte;
+    /// Abbreviated alias for [`Unit::Yottabyte`].
+    pub const YB: Unit = Unit::Yottabyte;
+    /// Abbreviated alias for [`Unit::Ronnabyte`].
+    pub const RB: Unit = Unit::Ronnabyte;
+    /// Abbreviated alias for [`Unit::Quettabyte`].
+    pub const QB: Unit = Unit::Quettabyte;
+
+    /// Abbreviated alias for [`Unit::Kibibyte`].
+    pub const KiB: Unit = Unit::Kibibyte;
+    /// Abbreviated alias for [`Unit::Mebibyte`].
+    pub const MiB: Unit = Unit::Mebibyte;
+    /// Abbreviated alias for [`Unit::Gibibyte`].
+    pub const GiB: Unit = Unit::Gibibyte;
+    /// Abbreviated alias for [`Unit::Tebibyte`].
+    pub const TiB: Unit = Unit::Tebibyte;
+    /// Abbreviated alias for [`Unit::Pebibyte`].
+    pub const PiB: Unit = Unit::Pebibyte;
+    /// Abbreviated alias for [`Unit::Exbibyte`].
+    pub const EiB: Unit = Unit::Exbibyte;
+    /// Abbreviated alias for [`Unit::Zebibyte`].
+    pub const ZiB: Unit = Unit::Zebibyte;
+    /// Abbreviated alias for [`Unit::Yobibyte`].
+    pub const YiB: Unit = Unit::Yobibyte;
+    /// Abbreviated alias for [`Unit::Robibyte`].
+    pub const RiB: Unit = Unit::Robibyte;
+    /// Abbreviated alias for [`Unit::Quebibyte`].
+    pub const QiB: Unit = Unit::Quebibyte;
+}
+
+const BASE2_UNITS: [Unit; 11] = [
+    Unit::Byte,
+    Unit::Kibibyte,
+    Unit::Mebibyte,
+    Unit::Gibibyte,
+    Unit::Tebibyte,
+    Unit::Pebibyte,
+    Unit::Exbibyte,
+    Unit::Zebibyte,
+    Unit::Yobibyte,
+    Unit::Robibyte,
+    Unit::Quebibyte,
+];
+
+const BASE10_UNITS: [Unit; 11] = [
+    Unit::Byte,
+    Unit::Kilobyte,
+    Unit::Megabyte,
+    Unit::Gigabyte,
+    Unit::Terabyte,
+    Unit::Petabyte,
+    Unit::Exabyte,
+    Unit::Zettabyte,
+    Unit::Yottabyte,
+    Unit::Ronnabyte,
+    Unit::Quettabyte,
+];
+
+impl Unit {
+    /// Returns the multiplier of the unit, i.e. the number of bytes in one of this unit.
+    ///
+    /// Units from [`Unit::Zettabyte`] and [`Unit::Zebibyte`] up are too large for an `i64` (and for
+    /// the [`Size`](crate::Size) it backs) and saturate at `i64::MAX`; use [`Unit::bytes_wide()`]
+    /// to obtain their exact multiplier.
+    ///
+    /// ```
+    /// use size::Unit;
+    ///
+    /// assert_eq!(Unit::KiB.bytes(), 1024);
+    /// assert_eq!(Unit::Terabyte.bytes(), 1_000_000_000_000);
+    /// assert_eq!(Unit::QiB.bytes(), i64::MAX);
+    /// ```
+    pub const fn bytes(self) -> i64 {
+        let bytes = self.bytes_wide();
+        if bytes > i64::MAX as i128 {
+            i64::MAX
+        } else {
+            bytes as i64
+        }
+    }
+
+    /// Returns the multiplier of the unit as an `i128`, which is exact for every unit, including
+    /// those too large for [`Unit::bytes()`]. These may be used with a
+    /// [`WideSize`](crate::WideSize).
+    ///
+    /// ```
+    /// use size::Unit;
+    ///
+    /// assert_eq!(Unit::ZB.bytes_wide(), 1_000_000_000_000_000_000_000);
+    /// assert_eq!(Unit::QiB.bytes_wide(), 1 << 100);
+    /// ```
+    pub const fn bytes_wide(self) -> i128 {
+        use self::Unit::*;
+
+        match self {
+            Byte => 1,
+
+            Kilobyte => 1000,
+            Megabyte => 1000 * Kilobyte.bytes_wide(),
+            Gigabyte => 1000 * Megabyte.bytes_wide(),
+            Terabyte => 1000 * Gigabyte.bytes_wide(),
+            Petabyte => 1000 * Terabyte.bytes_wide(),
+            Exabyte => 1000 * Petabyte.bytes_wide(),
+            Zettabyte => 1000 * Exabyte.bytes_wide(),
+            Yottabyte => 1000 * Zettabyte.bytes_wide(),
+            Ronnabyte => 1000 * Yottabyte.bytes_wide(),
+            Quettabyte => 1000 * Ronnabyte.bytes_wide(),
+
+            Kibibyte => 1 << 10,
+            Mebibyte => 1 << 20,
+            Gibibyte => 1 << 30,
+            Tebibyte => 1 << 40,
+            Pebibyte => 1 << 50,
+            Exbibyte => 1 << 60,
+            Zebibyte => 1 << 70,
+            Yobibyte => 1 << 80,
+            Robibyte => 1 << 90,
+            Quebibyte => 1 << 100,
+        }
+    }
+
+    /// Returns the [`Base`] the unit belongs to, or `None` for [`Unit::Byte`], which is shared by
+    /// all bases.
+    pub const fn base(self) -> Option<Base> {
+        use self::Unit::*;
+
+        match self {
+            Byte => None,
+            Kilobyte | Megabyte | Gigabyte | Terabyte | Petabyte | Exabyte | Zettabyte
+            | Yottabyte | Ronnabyte | Quettabyte => Some(Base::Base10),
+            Kibibyte | Mebibyte | Gibibyte | Tebibyte | Pebibyte | Exbibyte | Zebibyte
+            | Yobibyte | Robibyte | Quebibyte => Some(Base::Base2),
+        }
+    }
+
+    /// Returns the abbreviated name (symbol) of the unit, e.g. "KiB" or "MB".
+    pub const fn short_name(self) -> &'static str {
+        self.text().3
+    }
+
+    /// Returns the unabridged, singular, lowercase name of the unit, e.g. "kibibyte" or "megabyte".
+    pub const fn long_name(self) -> &'static str {
+        self.text().0
+    }
+
+    /// Returns an iterator over all units in the provided [`Base`], from smallest to largest and
+    /// starting with [`Unit::Byte`]. [`Base::Jedec`] and [`Base::Auto`] iterate over the base-2
+    /// units, as neither can pick a base without a value.
+    ///
+    /// ```
+    /// use size::{Base, Unit};
+    ///
+    /// let mut units = Unit::iter(Base::Base2);
+    /// assert_eq!(units.next(), Some(Unit::Byte));
+    /// assert_eq!(units.next(), Some(Unit::KiB));
+    /// assert_eq!(units.last(), Some(Unit::QiB));
+    /// ```
+    pub fn iter(base: Base) -> impl Iterator<Item = Unit> {
+        match base {
+            Base::Base2 | Base::Jedec | Base::Auto => BASE2_UNITS.iter().copied(),
+            Base::Base10 => BASE10_UNITS.iter().copied(),
+        }
+    }
+
+    /// Returns the unit of the same order of magnitude in the other base (e.g. [`Unit::Kilobyte`]
+    /// for [`Unit::Kibibyte`] and vice versa), which names the unit under [`Base::Jedec`].
+    pub(crate) const fn counterpart(self) -> Unit {
+        let mut i = 0;
+        while i < BASE2_UNITS.len() {
+            if BASE2_UNITS[i] as u8 == self as u8 {
+                return BASE10_UNITS[i];
+            } else if BASE10_UNITS[i] as u8 == self as u8 {
+                return BASE2_UNITS[i];
+            }
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the symbol of the unit as specified by SI and ISO/IEC 80000-13, which only differs
+    /// from [`Unit::short_name()`] in the lowercase "k" of "kB".
+    pub(crate) const fn si_symbol(&self) -> &'static str {
+        match self {
+            Unit::Kilobyte => "kB",
+            _ => self.text().3,
+        }
+    }
+
+    #[rustfmt::skip]
+    pub(crate) const fn text(&self) -> (&'static str, &'static str, &'static str, &'static str, &'static str) {
+        use self::Unit::*;
+
+        match self {
+            Byte => ("byte", "Byte", "b", "B", ""),
+
+            Kilobyte => ("kilobyte", "Kilobyte", "kb", "KB", "k"),
+            Megabyte => ("megabyte", "Megabyte", "mb", "MB", "M"),
+            Gigabyte => ("gigabyte", "Gigabyte", "gb", "GB", "G"),
+            Terabyte => ("terabyte", "Terabyte", "tb", "TB", "T"),
+            Petabyte => ("petabyte", "Petabyte", "pb", "PB", "P"),
+            Exabyte  => ("exabyte",  "Exabyte",  "eb", "EB", "E"),
+            Zettabyte  => ("zettabyte",  "Zettabyte",  "zb", "ZB", "Z"),
+            Yottabyte  => ("yottabyte",  "Yottabyte",  "yb", "YB", "Y"),
+            Ronnabyte  => ("ronnabyte",  "Ronnabyte",  "rb", "RB", "R"),
+            Quettabyte => ("quettabyte", "Quettabyte", "qb", "QB", "Q"),
+
+            Kibibyte => ("kibibyte", "Kibibyte", "kib", "KiB", "K"),
+            Mebibyte => ("mebibyte", "Mebibyte", "mib", "MiB", "M"),
+            Gibibyte => ("gibibyte", "Gibibyte", "gib", "GiB", "G"),
+            Pebibyte => ("pebibyte", "Pebibyte", "pib", "PiB", "P"),
+            Tebibyte => ("tebibyte", "Tebibyte", "tib", "TiB", "T"),
+            Exbibyte => ("exbibyte", "Exbibyte", "eib", "EiB", "E"),
+            Zebibyte  => ("zebibyte",  "Zebibyte",  "zib", "ZiB", "Z"),
+            Yobibyte  => ("yobibyte",  "Yobibyte",  "yib", "YiB", "Y"),
+            Robibyte  => ("robibyte",  "Robibyte",  "rib", "RiB", "R"),
+            Quebibyte => ("quebibyte", "Quebibyte", "qib", "QiB", "Q"),
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.short_name())
+    }
+}
+
+impl FromStr for Unit {
+    type Err = ParseSizeError;
+
+    /// Parses a unit from either its abbreviated or unabridged name, singular or plural, ignoring
+    /// ASCII case and surrounding whitespace (e.g. "KiB", "kib", "Mebibytes", or "gigabyte").
+    fn from_str(s: &str) -> Result<Unit, Self::Err> {
+        match Unit::from_name(s.trim().as_bytes()) {
+            Some(unit) => Ok(unit),
+            None => Err(ParseSizeError),
+        }
+    }
+}
+
+/// ASCII case-insensitive comparison of two byte strings, usable in a `const` context.
+const fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Strips any trailing "s" characters so both singular and plural unit names can be matched.
+pub(crate) const fn trim_plural(name: &[u8]) -> &[u8] {
+    let mut len = name.len();
+    while len > 0 && (name[len - 1] == b's' || name[len - 1] == b'S') {
+        len -= 1;
+    }
+    name.split_at(len).0
+}
+
+impl Unit {
+    /// Looks up a unit by its abbreviated or unabridged name, singular or plural, ignoring ASCII
+    /// case. This is the `const` implementation behind `impl FromStr for Unit`.
+    pub(crate) const fn from_name(name: &[u8]) -> Option<Unit> {
+        let name = trim_plural(name);
+
+        let mut i = 0;
+        while i < BASE2_UNITS.len() + BASE10_UNITS.len() {
+            let unit = if i < BASE2_UNITS.len() {
+                BASE2_UNITS[i]
+            } else {
+                BASE10_UNITS[i - BASE2_UNITS.len()]
+            };
+            if eq_ignore_ascii_case(name, unit.short_name().as_bytes())
+                || eq_ignore_ascii_case(name, unit.long_name().as_bytes())
+            {
+                return Some(unit);
+            }
+            i += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_multipliers() {
+        for (base, multiplier) in [(Base::Base2, 1024), (Base::Base10, 1000)] {
+            let mut expected = 1;
+            for unit in Unit::iter(base) {
+                assert_eq!(unit.bytes_wide(), expected);
+                assert_eq!(unit.bytes() as i128, expected.min(i64::MAX as i128));
+                assert!(unit == Unit::Byte || unit.base() == Some(base));
+                expected *= multiplier;
+            }
+        }
+    }
+
+    #[test]
+    fn unit_counterparts() {
+        for (base2, base10) in Unit::iter(Base::Base2).zip(Unit::iter(Base::Base10)) {
+            assert_eq!(base2.counterpart(), base10);
+            assert_eq!(base10.counterpart(), base2);
+        }
+        assert_eq!(Unit::Byte.counterpart(), Unit::Byte);
+        assert_eq!(Unit::iter(Base::Jedec).nth(2), Some(Unit::MiB));
+    }
+
+    #[test]
+    fn resolve_auto_base() {
+        let resolve = |bytes: u128| Base::Auto.resolve(bytes);
+        assert_eq!(resolve(0), Base::Base10);
+        assert_eq!(resolve(1000), Base::Base10);
+        assert_eq!(resolve(1024), Base::Base2);
+        assert_eq!(resolve(4096), Base::Base2);
+        assert_eq!(resolve(1_024_000), Base::Base10);
+        assert_eq!(resolve(1_048_576_000), Base::Base10);
+        assert_eq!(resolve(8 << 30), Base::Base2);
+        assert_eq!(resolve(4_000_000_000_000), Base::Base10);
+        assert_eq!(Base::Jedec.resolve(1000), Base::Jedec);
+    }
+
+    #[test]
+    fn unit_names() {
+        assert_eq!(Unit::KiB.short_name(), "KiB");
+        assert_eq!(Unit::KiB.long_name(), "kibibyte");
+        assert_eq!(Unit::EB.short_name(), "EB");
+        assert_eq!(Unit::EB.long_name(), "exabyte");
+        assert_eq!(Unit::Byte.short_name(), "B");
+    }
+
+    #[test]
+    fn parse_unit() {
+        let tests = [
+            ("b", Unit::Byte),
+            ("Bytes", Unit::Byte),
+            ("KB", Unit::Kilobyte),
+            ("kib", Unit::Kibibyte),
+            (" MiB ", Unit::Mebibyte),
+            ("gigabytes", Unit::Gigabyte),
+            ("TEBIBYTE", Unit::Tebibyte),
+            ("pb", Unit::Petabyte),
+            ("Exbibytes", Unit::Exbibyte),
+            ("ZB", Unit::Zettabyte),
+            ("yib", Unit::Yobibyte),
+            ("ronnabytes", Unit::Ronnabyte),
+            ("Quebibyte", Unit::Quebibyte),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(input.parse(), Ok(expected), "parsing {:?}", input);
+        }
+
+        assert_eq!("".parse::<Unit>(), Err(ParseSizeError));
+        assert_eq!("XB".parse::<Unit>(), Err(ParseSizeError));
+        assert_eq!("kibi".parse::<Unit>(), Err(ParseSizeError));
+    }
+}
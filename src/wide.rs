@@ -0,0 +1,400 @@
+use crate::from_str::parse_bytes;
+use crate::{ByteCount, IntoSize, ParseSizeError, Size, TryFromSizeError, Unit};
+use core::convert::TryFrom;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A 128-bit counterpart to [`Size`], backed by an `i128` instead of an `i64`.
+///
+/// A `Size` tops out at just under 8 EiB, which may not be enough to express aggregate sizes
+/// (e.g. the total storage across many machines). A `WideSize` can express sizes of up to about
+/// 170 million QB (quettabytes, 10^30 bytes) in either direction, and is the only size type that
+/// can be created in terms of the units above exabytes/exbibytes, i.e. [`Unit::Zettabyte`] through
+/// [`Unit::Quettabyte`] and [`Unit::Zebibyte`] through [`Unit::Quebibyte`].
+///
+/// `WideSize` offers generic constructors in the style of those of `Size` (clamping out-of-range
+/// values to [`WideSize::MIN`] or [`WideSize::MAX`]), is formatted by the same
+/// [`SizeFormatter`](crate::SizeFormatter) rules, and may be parsed from text. The one formatting
+/// difference is that exabytes are written with the same fractional digits as every other unit
+/// ("1.50 EiB"), while a `Size` or [`ByteCount`] keeps writing them as a whole number ("2 EiB").
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+#[cfg_attr(feature = "std", doc = "```")]
+/// use size::{Size, WideSize};
+/// use std::convert::TryFrom;
+///
+/// let fleet: WideSize = [WideSize::from_eib(6), WideSize::from_eib(5)].iter().sum();
+/// assert_eq!(fleet, "11 EiB".parse().unwrap());
+/// assert_eq!(fleet.to_string(), "11.0 EiB");
+/// assert!(Size::try_from(fleet).is_err());
+///
+/// let archive = WideSize::from_zb(3) + WideSize::from(Size::from_gb(500));
+/// assert_eq!(archive.format().with_base(size::Base::Base10).to_string(), "3.00 ZB");
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
+pub struct WideSize {
+    bytes: i128,
+}
+
+impl WideSize {
+    /// Zero value (0 bytes)
+    pub const ZERO: Self = Self { bytes: 0 };
+
+    /// The largest size that can be expressed by a `WideSize` (`i128::MAX` bytes).
+    pub const MAX: Self = Self { bytes: i128::MAX };
+
+    /// The smallest (most negative) size that can be expressed by a `WideSize` (`i128::MIN`
+    /// bytes).
+    pub const MIN: Self = Self { bytes: i128::MIN };
+
+    /// Initialize a `WideSize` from the provided value, in bytes. This is a constant function and
+    /// may be used in a `const` context. See [`Size::from_const()`].
+    pub const fn from_const(bytes: i128) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the number of bytes in the `WideSize`.
+    pub const fn bytes(&self) -> i128 {
+        self.bytes
+    }
+
+    /// Initialize a `WideSize` from the provided value, in bytes.
+    pub fn from_bytes<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Byte)
+    }
+
+    /// Express a size as `value` of the provided [`Unit`]. The result saturates at
+    /// [`WideSize::MIN`] or [`WideSize::MAX`].
+    ///
+    /// The per-unit shortcuts (e.g. [`WideSize::from_zib()`]) may be more readable.
+    pub fn from_units<T: IntoSize>(value: T, unit: Unit) -> Self {
+        Self {
+            bytes: value.into_bytes(unit.bytes_wide()),
+        }
+    }
+
+    #[inline]
+    /// Express a size in kilobytes. See [`WideSize::from_units()`].
+    pub fn from_kb<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Kilobyte)
+    }
+    #[inline]
+    /// Express a size in megabytes. See [`WideSize::from_units()`].
+    pub fn from_mb<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Megabyte)
+    }
+    #[inline]
+    /// Express a size in gigabytes. See [`WideSize::from_units()`].
+    pub fn from_gb<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Gigabyte)
+    }
+    #[inline]
+    /// Express a size in terabytes. See [`WideSize::from_units()`].
+    pub fn from_tb<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Terabyte)
+    }
+    #[inline]
+    /// Express a size in petabytes. See [`WideSize::from_units()`].
+    pub fn from_pb<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Petabyte)
+    }
+    #[inline]
+    /// Express a size in exabytes. See [`WideSize::from_units()`].
+    pub fn from_eb<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Exabyte)
+    }
+    #[inline]
+    /// Express a size in zettabytes. See [`WideSize::from_units()`].
+    pub fn from_zb<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Zettabyte)
+    }
+    #[inline]
+    /// Express a size in yottabytes. See [`WideSize::from_units()`].
+    pub fn from_yb<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Yottabyte)
+    }
+    #[inline]
+    /// Express a size in ronnabytes. See [`WideSize::from_units()`].
+    pub fn from_rb<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Ronnabyte)
+    }
+    #[inline]
+    /// Express a size in quettabytes. See [`WideSize::from_units()`].
+    pub fn from_qb<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Quettabyte)
+    }
+    #[inline]
+    /// Express a size in kibibytes. See [`WideSize::from_units()`].
+    pub fn from_kib<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Kibibyte)
+    }
+    #[inline]
+    /// Express a size in mebibytes. See [`WideSize::from_units()`].
+    pub fn from_mib<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Mebibyte)
+    }
+    #[inline]
+    /// Express a size in gibibytes. See [`WideSize::from_units()`].
+    pub fn from_gib<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Gibibyte)
+    }
+    #[inline]
+    /// Express a size in tebibytes. See [`WideSize::from_units()`].
+    pub fn from_tib<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Tebibyte)
+    }
+    #[inline]
+    /// Express a size in pebibytes. See [`WideSize::from_units()`].
+    pub fn from_pib<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Pebibyte)
+    }
+    #[inline]
+    /// Express a size in exbibytes. See [`WideSize::from_units()`].
+    pub fn from_eib<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Exbibyte)
+    }
+    #[inline]
+    /// Express a size in zebibytes. See [`WideSize::from_units()`].
+    pub fn from_zib<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Zebibyte)
+    }
+    #[inline]
+    /// Express a size in yobibytes. See [`WideSize::from_units()`].
+    pub fn from_yib<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Yobibyte)
+    }
+    #[inline]
+    /// Express a size in robibytes. See [`WideSize::from_units()`].
+    pub fn from_rib<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Robibyte)
+    }
+    #[inline]
+    /// Express a size in quebibytes. See [`WideSize::from_units()`].
+    pub fn from_qib<T: IntoSize>(value: T) -> Self {
+        Self::from_units(value, Unit::Quebibyte)
+    }
+
+    /// Adds two sizes, returning `None` on overflow.
+    pub const fn checked_add(self, other: WideSize) -> Option<WideSize> {
+        match self.bytes.checked_add(other.bytes) {
+            Some(bytes) => Some(WideSize { bytes }),
+            None => None,
+        }
+    }
+
+    /// Subtracts `other` from `self`, returning `None` on overflow.
+    pub const fn checked_sub(self, other: WideSize) -> Option<WideSize> {
+        match self.bytes.checked_sub(other.bytes) {
+            Some(bytes) => Some(WideSize { bytes }),
+            None => None,
+        }
+    }
+
+    /// Adds two sizes, saturating at [`WideSize::MIN`] or [`WideSize::MAX`] instead of
+    /// overflowing.
+    pub const fn saturating_add(self, other: WideSize) -> WideSize {
+        WideSize {
+            bytes: self.bytes.saturating_add(other.bytes),
+        }
+    }
+
+    /// Subtracts `other` from `self`, saturating at [`WideSize::MIN`] or [`WideSize::MAX`] instead
+    /// of overflowing.
+    pub const fn saturating_sub(self, other: WideSize) -> WideSize {
+        WideSize {
+            bytes: self.bytes.saturating_sub(other.bytes),
+        }
+    }
+
+    /// Parse a `WideSize` from text, accepting the same input as [`Size::from_str()`] as well as
+    /// the units above exabytes/exbibytes.
+    ///
+    /// ```
+    /// use size::WideSize;
+    ///
+    /// assert_eq!(WideSize::from_str("1.5 YiB"), Ok(WideSize::from_zib(1536)));
+    /// assert_eq!(WideSize::from_str("-2 ronnabytes"), Ok(WideSize::from_rb(-2)));
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<WideSize, ParseSizeError> {
+        s.parse()
+    }
+}
+
+impl core::fmt::Debug for WideSize {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(fmt, "{} bytes", self.bytes())
+    }
+}
+
+impl core::str::FromStr for WideSize {
+    type Err = ParseSizeError;
+
+    fn from_str(s: &str) -> Result<WideSize, Self::Err> {
+        parse_bytes(s.as_bytes()).map(WideSize::from_const)
+    }
+}
+
+/* Lossless conversions to and from the narrower size types and `i128` */
+
+impl From<Size> for WideSize {
+    fn from(size: Size) -> WideSize {
+        WideSize::from_const(size.bytes() as i128)
+    }
+}
+
+impl From<ByteCount> for WideSize {
+    fn from(count: ByteCount) -> WideSize {
+        WideSize::from_const(count.bytes() as i128)
+    }
+}
+
+impl TryFrom<WideSize> for Size {
+    type Error = TryFromSizeError;
+
+    fn try_from(size: WideSize) -> Result<Size, Self::Error> {
+        Size::try_from(size.bytes)
+    }
+}
+
+impl TryFrom<WideSize> for ByteCount {
+    type Error = TryFromSizeError;
+
+    fn try_from(size: WideSize) -> Result<ByteCount, Self::Error> {
+        u64::try_from(size.bytes)
+            .map(ByteCount::from_const)
+            .map_err(|_| TryFromSizeError(()))
+    }
+}
+
+impl From<i128> for WideSize {
+    fn from(bytes: i128) -> WideSize {
+        WideSize::from_const(bytes)
+    }
+}
+
+impl From<WideSize> for i128 {
+    fn from(size: WideSize) -> i128 {
+        size.bytes
+    }
+}
+
+/* Arithmetic, following the semantics of the `i128` operators */
+
+impl Add<WideSize> for WideSize {
+    type Output = WideSize;
+
+    fn add(self, other: WideSize) -> Self::Output {
+        WideSize::from_const(self.bytes + other.bytes)
+    }
+}
+
+impl Sub<WideSize> for WideSize {
+    type Output = WideSize;
+
+    fn sub(self, other: WideSize) -> Self::Output {
+        WideSize::from_const(self.bytes - other.bytes)
+    }
+}
+
+impl Neg for WideSize {
+    type Output = WideSize;
+
+    fn neg(self) -> Self::Output {
+        WideSize::from_const(-self.bytes)
+    }
+}
+
+impl AddAssign<WideSize> for WideSize {
+    fn add_assign(&mut self, other: WideSize) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<WideSize> for WideSize {
+    fn sub_assign(&mut self, other: WideSize) {
+        *self = *self - other;
+    }
+}
+
+impl Sum for WideSize {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |total, size| total + size)
+    }
+}
+
+impl<'a> Sum<&'a WideSize> for WideSize {
+    fn sum<I: Iterator<Item = &'a WideSize>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |total, size| total + *size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructors() {
+        assert_eq!(WideSize::from_zib(1).bytes(), 1 << 70);
+        assert_eq!(WideSize::from_qb(2).bytes(), 2_000_000_000_000_000_000_000_000_000_000);
+        assert_eq!(WideSize::from_qib(-1).bytes(), -(1 << 100));
+        assert_eq!(WideSize::from_eib(u64::MAX), WideSize::from_const((u64::MAX as i128) << 60));
+        assert_eq!(WideSize::from_qb(u64::MAX), WideSize::MAX);
+        assert_eq!(WideSize::from_qb(i64::MIN), WideSize::MIN);
+        assert_eq!(WideSize::from_units(3_u8, Unit::Yottabyte), WideSize::from_yb(3));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(WideSize::from(Size::MIN).bytes(), i64::MIN as i128);
+        assert_eq!(WideSize::from(ByteCount::MAX).bytes(), u64::MAX as i128);
+        assert_eq!(Size::try_from(WideSize::from_eib(-8)), Ok(Size::MIN));
+        assert!(Size::try_from(WideSize::from_eib(8)).is_err());
+        assert_eq!(ByteCount::try_from(WideSize::from_eib(15)), Ok(ByteCount::from_eib(15)));
+        assert!(ByteCount::try_from(WideSize::from_eib(16)).is_err());
+        assert!(ByteCount::try_from(WideSize::from_bytes(-1)).is_err());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let total = WideSize::from_eib(6) + WideSize::from_eib(6);
+        assert_eq!(total, WideSize::from_eib(12));
+        assert_eq!(-total, WideSize::from_eib(-12));
+        assert_eq!(WideSize::MAX.checked_add(total), None);
+        assert_eq!(WideSize::MIN.saturating_sub(total), WideSize::MIN);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("12 EiB".parse(), Ok(WideSize::from_eib(12)));
+        assert_eq!("1 ZB".parse(), Ok(WideSize::from_zb(1)));
+        assert_eq!("2.5 zebibytes".parse(), Ok(WideSize::from_eib(2560)));
+        assert_eq!("-3 RiB".parse(), Ok(WideSize::from_rib(-3)));
+        assert_eq!("100 QB".parse(), Ok(WideSize::from_qb(100)));
+        assert_eq!("1000000000 QB".parse::<WideSize>(), Err(ParseSizeError));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn format() {
+        assert_eq!(WideSize::from_eib(12).to_string(), "12.0 EiB");
+        assert_eq!(WideSize::from_pib(1536).to_string(), "1.50 EiB");
+        assert_eq!(WideSize::from_eib(512).to_string(), "512 EiB");
+        assert_eq!(WideSize::from_zib(1).to_string(), "1.00 ZiB");
+        assert_eq!(WideSize::from_yib(42).to_string(), "42.0 YiB");
+        assert_eq!(WideSize::from_rib(-512).to_string(), "-512 RiB");
+        assert_eq!(WideSize::from_qib(3).to_string(), "3.00 QiB");
+        assert_eq!(WideSize::MAX.to_string(), "134217728 QiB");
+        assert_eq!(WideSize::MIN.to_string(), "-134217728 QiB");
+
+        let base10 = |size: WideSize| size.format().with_base(crate::Base::Base10).to_string();
+        assert_eq!(base10(WideSize::from_zb(1)), "1.00 ZB");
+        assert_eq!(base10(WideSize::from_yb(250)), "250 YB");
+        assert_eq!(base10(WideSize::from_rb(99)), "99.0 RB");
+        assert_eq!(base10(WideSize::from_qb(7)), "7.00 QB");
+        assert_eq!(base10(WideSize::from_pb(2500)), "2.50 EB");
+        assert_eq!(format!("{:?}", WideSize::from_kib(1)), "1024 bytes");
+
+        // Sizes backed by 64 bits are still written in whole exabytes.
+        assert_eq!(crate::Size::from_pib(1536).to_string(), "2 EiB");
+    }
+}